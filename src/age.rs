@@ -0,0 +1,215 @@
+//! Calculation of ages in Japan.
+//!
+//! Two ways of counting ages are in use.
+//! 満年齢 starts from `0` at birth and increases every year,
+//! while 数え年 starts from `1` at birth and increases on every January 1.
+//!
+//! Under 年齢計算ニ関スル法律, the age increases at the end of the day before the birthday,
+//! which matters for school enrollment and insurance.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chrono::NaiveDate;
+//! use koyomi_rs::age;
+//!
+//! let birth = NaiveDate::from_ymd_opt(2000, 4, 2).unwrap();
+//! let date = NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+//!
+//! assert_eq!(Some(23), age::full_age(&birth, &date));
+//! assert_eq!(Some(24), age::legal_age(&birth, &date));
+//! assert_eq!(Some(25), age::kazoedoshi(&birth, &date));
+//! ```
+use crate::internal::to_naive_date;
+use chrono::{Datelike, NaiveDate};
+
+/// Returns 満年齢 which increases on the birthday.
+///
+/// For those who were born on February 29, the age increases on March 1 in common years.
+/// Returns `None` if the date is before the birth date.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use koyomi_rs::age::full_age;
+///
+/// let birth = NaiveDate::from_ymd_opt(2000, 2, 29).unwrap();
+///
+/// let date = NaiveDate::from_ymd_opt(2023, 2, 28).unwrap();
+/// assert_eq!(Some(22), full_age(&birth, &date));
+///
+/// let date = NaiveDate::from_ymd_opt(2023, 3, 1).unwrap();
+/// assert_eq!(Some(23), full_age(&birth, &date));
+/// ```
+pub fn full_age<B: Datelike, D: Datelike>(birth: &B, date: &D) -> Option<u32> {
+    let birth = to_naive_date(birth);
+    let date = to_naive_date(date);
+
+    years_since(&birth, &date)
+}
+
+/// Returns 数え年 which is `1` at birth and increases on every January 1.
+///
+/// Returns `None` if the date is before the birth date.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use koyomi_rs::age::kazoedoshi;
+///
+/// let birth = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+///
+/// assert_eq!(Some(1), kazoedoshi(&birth, &birth));
+/// assert_eq!(Some(2), kazoedoshi(&birth, &NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()));
+/// ```
+pub fn kazoedoshi<B: Datelike, D: Datelike>(birth: &B, date: &D) -> Option<u32> {
+    let birth = to_naive_date(birth);
+    let date = to_naive_date(date);
+
+    (birth <= date).then(|| (date.year() - birth.year()) as u32 + 1)
+}
+
+/// Returns 満年齢 as defined by 年齢計算ニ関スル法律,
+/// which increases on the day before the birthday.
+///
+/// For those who were born on February 29, the age increases on February 28 in every year.
+/// Returns `None` if the date is before the birth date.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use koyomi_rs::age::legal_age;
+///
+/// // Those who were born on April 1 enter school a year earlier than those born on April 2.
+/// let birth = NaiveDate::from_ymd_opt(2018, 4, 1).unwrap();
+/// let date = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+///
+/// assert_eq!(Some(6), legal_age(&birth, &date));
+/// ```
+pub fn legal_age<B: Datelike, D: Datelike>(birth: &B, date: &D) -> Option<u32> {
+    let birth = to_naive_date(birth);
+    let date = to_naive_date(date);
+    if date < birth {
+        return None;
+    }
+
+    // The age on the date is the same as 満年齢 on the next day.
+    match date.succ_opt() {
+        Some(next) => years_since(&birth, &next),
+        // The day after `NaiveDate::MAX` would be January 1,
+        // when the age increases only for those who were born on January 1.
+        None => {
+            years_since(&birth, &date).map(|y| y + ((birth.month(), birth.day()) == (1, 1)) as u32)
+        }
+    }
+}
+
+fn years_since(from: &NaiveDate, until: &NaiveDate) -> Option<u32> {
+    if until < from {
+        return None;
+    }
+
+    let years = until.year() - from.year();
+    let years = if (until.month(), until.day()) < (from.month(), from.day()) {
+        years - 1
+    } else {
+        years
+    };
+
+    Some(years as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[rstest]
+    #[case((2000, 5, 10), (2000, 5, 10), 0)]
+    #[case((2000, 5, 10), (2024, 5, 9), 23)]
+    #[case((2000, 5, 10), (2024, 5, 10), 24)]
+    #[case((2000, 2, 29), (2023, 2, 28), 22)]
+    #[case((2000, 2, 29), (2023, 3, 1), 23)]
+    #[case((2000, 2, 29), (2024, 2, 28), 23)]
+    #[case((2000, 2, 29), (2024, 2, 29), 24)]
+    fn 誕生日に満年齢が加算される(
+        #[case] birth: (i32, u32, u32),
+        #[case] today: (i32, u32, u32),
+        #[case] expect: u32,
+    ) {
+        let birth = date(birth.0, birth.1, birth.2);
+        let today = date(today.0, today.1, today.2);
+
+        assert_eq!(Some(expect), full_age(&birth, &today));
+    }
+
+    #[rstest]
+    #[case((2000, 5, 10), (2000, 5, 10), 0)]
+    #[case((2000, 5, 10), (2024, 5, 8), 23)]
+    #[case((2000, 5, 10), (2024, 5, 9), 24)]
+    #[case((2000, 1, 1), (2024, 12, 30), 24)]
+    #[case((2000, 1, 1), (2024, 12, 31), 25)]
+    #[case((2000, 2, 29), (2023, 2, 27), 22)]
+    #[case((2000, 2, 29), (2023, 2, 28), 23)]
+    #[case((2000, 2, 29), (2024, 2, 28), 24)]
+    #[case((2000, 3, 1), (2023, 2, 27), 22)]
+    #[case((2000, 3, 1), (2023, 2, 28), 23)]
+    #[case((2000, 3, 1), (2024, 2, 28), 23)]
+    #[case((2000, 3, 1), (2024, 2, 29), 24)]
+    fn 誕生日の前日に法律上の年齢が加算される(
+        #[case] birth: (i32, u32, u32),
+        #[case] today: (i32, u32, u32),
+        #[case] expect: u32,
+    ) {
+        let birth = date(birth.0, birth.1, birth.2);
+        let today = date(today.0, today.1, today.2);
+
+        assert_eq!(Some(expect), legal_age(&birth, &today));
+    }
+
+    #[rstest]
+    #[case((2000, 1, 1), NaiveDate::MAX.year() - 2000 + 1)]
+    #[case((2000, 1, 2), NaiveDate::MAX.year() - 2000)]
+    #[case((2000, 12, 31), NaiveDate::MAX.year() - 2000)]
+    fn 最大の日付でも法律上の年齢を計算できる(
+        #[case] birth: (i32, u32, u32),
+        #[case] expect: i32,
+    ) {
+        let birth = date(birth.0, birth.1, birth.2);
+
+        assert_eq!(Some(expect as u32), legal_age(&birth, &NaiveDate::MAX));
+    }
+
+    #[rstest]
+    #[case((2024, 1, 1), (2024, 12, 31), 1)]
+    #[case((2024, 12, 31), (2025, 1, 1), 2)]
+    #[case((2000, 5, 10), (2024, 5, 9), 25)]
+    fn 元日に数え年が加算される(
+        #[case] birth: (i32, u32, u32),
+        #[case] today: (i32, u32, u32),
+        #[case] expect: u32,
+    ) {
+        let birth = date(birth.0, birth.1, birth.2);
+        let today = date(today.0, today.1, today.2);
+
+        assert_eq!(Some(expect), kazoedoshi(&birth, &today));
+    }
+
+    #[rstest]
+    fn 生年月日より前の日付では年齢を計算できない() {
+        let birth = date(2000, 5, 10);
+        let today = date(2000, 5, 9);
+
+        assert!(full_age(&birth, &today).is_none());
+        assert!(legal_age(&birth, &today).is_none());
+        assert!(kazoedoshi(&birth, &today).is_none());
+    }
+}
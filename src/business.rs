@@ -0,0 +1,380 @@
+use crate::day::JapaneseWeekday;
+use crate::internal::to_naive_date;
+use crate::provider::{HolidayProvider, NationalHolidays};
+
+use chrono::{Datelike, NaiveDate};
+
+/// The longest run of consecutive non-business days searched for a business day.
+const MAX_NON_BUSINESS_DAYS: usize = 366;
+
+/// Business calendar
+///
+/// It treats weekends and holidays as non-business days.
+/// Weekends are Saturday and Sunday by default, and can be configured with [`BusinessCalendar::with_weekend`].
+/// Holidays are Japanese national holidays by default, and can be replaced with [`BusinessCalendar::with_holidays`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BusinessCalendar<P = NationalHolidays> {
+    provider: P,
+    weekend: Vec<JapaneseWeekday>,
+}
+
+impl BusinessCalendar {
+    /// Generate a calendar whose weekends are Saturday and Sunday.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::BusinessCalendar;
+    ///
+    /// let calendar = BusinessCalendar::new();
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 7).unwrap(); // Sun
+    ///
+    /// assert!(!calendar.is_business_day(&date));
+    /// ```
+    pub fn new() -> Self {
+        Self::with_weekend(&[JapaneseWeekday::Do, JapaneseWeekday::Nichi])
+    }
+
+    /// Generate a calendar with the specified weekends.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{BusinessCalendar, JapaneseWeekday};
+    ///
+    /// // Only Sunday is a weekend.
+    /// let calendar = BusinessCalendar::with_weekend(&[JapaneseWeekday::Nichi]);
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 6).unwrap(); // Sat
+    ///
+    /// assert!(calendar.is_business_day(&date));
+    /// ```
+    pub fn with_weekend(weekend: &[JapaneseWeekday]) -> Self {
+        let mut weekend = weekend.to_vec();
+        weekend.sort();
+        weekend.dedup();
+
+        Self {
+            provider: NationalHolidays,
+            weekend,
+        }
+    }
+}
+
+impl<P: HolidayProvider> BusinessCalendar<P> {
+    /// Returns the date after the specified number of business days.
+    ///
+    /// Returns the date itself when `days` is zero, even if it's not a business day.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::BusinessCalendar;
+    ///
+    /// let calendar = BusinessCalendar::new();
+    /// let date = NaiveDate::from_ymd_opt(2024, 4, 26).unwrap();
+    ///
+    /// // Golden week is skipped.
+    /// let expect = NaiveDate::from_ymd_opt(2024, 5, 8).unwrap();
+    /// assert_eq!(Some(expect), calendar.add_business_days(&date, 5));
+    /// ```
+    pub fn add_business_days<T: Datelike>(&self, date: &T, days: u64) -> Option<NaiveDate> {
+        (0..days).try_fold(to_naive_date(date), |d, _| self.next_business_day(&d))
+    }
+
+    /// Returns the number of business days between the specified dates.
+    /// Both `from` and `until` are included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::BusinessCalendar;
+    ///
+    /// let calendar = BusinessCalendar::new();
+    /// let from = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+    /// let until = NaiveDate::from_ymd_opt(2024, 5, 31).unwrap();
+    ///
+    /// assert_eq!(21, calendar.business_days_between(&from, &until));
+    /// ```
+    pub fn business_days_between<T: Datelike>(&self, from: &T, until: &T) -> usize {
+        let until = to_naive_date(until);
+        to_naive_date(from)
+            .iter_days()
+            .take_while(|d| d <= &until)
+            .filter(|d| self.is_business_day(d))
+            .count()
+    }
+
+    /// Returns the business day or not.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::BusinessCalendar;
+    ///
+    /// let calendar = BusinessCalendar::new();
+    ///
+    /// assert!(calendar.is_business_day(&NaiveDate::from_ymd_opt(2024, 1, 4).unwrap()));
+    /// // New Year's Day
+    /// assert!(!calendar.is_business_day(&NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()));
+    /// // Saturday
+    /// assert!(!calendar.is_business_day(&NaiveDate::from_ymd_opt(2024, 1, 6).unwrap()));
+    /// ```
+    pub fn is_business_day<T: Datelike>(&self, date: &T) -> bool {
+        !self.weekend.contains(&JapaneseWeekday::from_datelike(date))
+            && self.provider.holiday(&to_naive_date(date)).is_none()
+    }
+
+    /// Returns the next business day after the specified date.
+    ///
+    /// Returns `None` when there is no business day, e.g. all days of the week are weekends.
+    /// It's also the case when a year passes without any business day.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::BusinessCalendar;
+    ///
+    /// let calendar = BusinessCalendar::new();
+    /// let date = NaiveDate::from_ymd_opt(2024, 5, 2).unwrap();
+    ///
+    /// let expect = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap();
+    /// assert_eq!(Some(expect), calendar.next_business_day(&date));
+    /// ```
+    pub fn next_business_day<T: Datelike>(&self, date: &T) -> Option<NaiveDate> {
+        if !self.has_business_day() {
+            return None;
+        }
+
+        to_naive_date(date)
+            .iter_days()
+            .skip(1)
+            .take(MAX_NON_BUSINESS_DAYS + 1)
+            .find(|d| self.is_business_day(d))
+    }
+
+    /// Returns the previous business day before the specified date.
+    ///
+    /// Returns `None` when there is no business day, e.g. all days of the week are weekends.
+    /// It's also the case when a year passes without any business day.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::BusinessCalendar;
+    ///
+    /// let calendar = BusinessCalendar::new();
+    /// let date = NaiveDate::from_ymd_opt(2024, 5, 7).unwrap();
+    ///
+    /// let expect = NaiveDate::from_ymd_opt(2024, 5, 2).unwrap();
+    /// assert_eq!(Some(expect), calendar.previous_business_day(&date));
+    /// ```
+    pub fn previous_business_day<T: Datelike>(&self, date: &T) -> Option<NaiveDate> {
+        if !self.has_business_day() {
+            return None;
+        }
+
+        to_naive_date(date)
+            .iter_days()
+            .rev()
+            .skip(1)
+            .take(MAX_NON_BUSINESS_DAYS + 1)
+            .find(|d| self.is_business_day(d))
+    }
+
+    /// Returns the date before the specified number of business days.
+    ///
+    /// Returns the date itself when `days` is zero, even if it's not a business day.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::BusinessCalendar;
+    ///
+    /// let calendar = BusinessCalendar::new();
+    /// let date = NaiveDate::from_ymd_opt(2024, 5, 8).unwrap();
+    ///
+    /// let expect = NaiveDate::from_ymd_opt(2024, 4, 26).unwrap();
+    /// assert_eq!(Some(expect), calendar.sub_business_days(&date, 5));
+    /// ```
+    pub fn sub_business_days<T: Datelike>(&self, date: &T, days: u64) -> Option<NaiveDate> {
+        (0..days).try_fold(to_naive_date(date), |d, _| self.previous_business_day(&d))
+    }
+
+    /// Replace the holidays with the holidays of the provider.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{BusinessCalendar, HolidayCalendar};
+    ///
+    /// let holidays = HolidayCalendar::new().with_fixed_date("年末年始休暇", 1, 2);
+    /// let calendar = BusinessCalendar::new().with_holidays(holidays);
+    /// let date = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    ///
+    /// let expect = NaiveDate::from_ymd_opt(2025, 1, 3).unwrap();
+    /// assert_eq!(Some(expect), calendar.next_business_day(&date));
+    /// ```
+    pub fn with_holidays<Q: HolidayProvider>(self, provider: Q) -> BusinessCalendar<Q> {
+        BusinessCalendar {
+            provider,
+            weekend: self.weekend,
+        }
+    }
+
+    fn has_business_day(&self) -> bool {
+        self.weekend.len() < 7
+    }
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom::HolidayCalendar;
+    use crate::day::JapaneseHoliday;
+    use rstest::rstest;
+
+    #[derive(Debug)]
+    struct EveryDay;
+
+    impl HolidayProvider for EveryDay {
+        type Holiday = JapaneseHoliday;
+
+        fn holiday(&self, _date: &NaiveDate) -> Option<Self::Holiday> {
+            Some(JapaneseHoliday::NewYearsDay)
+        }
+    }
+
+    #[rstest]
+    #[case((2024, 4, 26), 0, (2024, 4, 26))]
+    #[case((2024, 4, 26), 1, (2024, 4, 30))]
+    #[case((2024, 4, 26), 5, (2024, 5, 8))]
+    #[case((2024, 12, 27), 1, (2024, 12, 30))]
+    #[case((2024, 12, 31), 1, (2025, 1, 2))]
+    fn 営業日を加算できる(
+        #[case] date: (i32, u32, u32),
+        #[case] days: u64,
+        #[case] expect: (i32, u32, u32),
+    ) {
+        let calendar = BusinessCalendar::new();
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+        let expect = NaiveDate::from_ymd_opt(expect.0, expect.1, expect.2).unwrap();
+
+        assert_eq!(Some(expect), calendar.add_business_days(&date, days));
+        assert_eq!(Some(date), calendar.sub_business_days(&expect, days));
+    }
+
+    #[rstest]
+    #[case((2024, 5, 2), (2024, 5, 7))]
+    #[case((2024, 5, 3), (2024, 5, 7))]
+    #[case((2024, 9, 13), (2024, 9, 17))]
+    #[case((2025, 1, 10), (2025, 1, 14))]
+    fn 翌営業日を導出できる(
+        #[case] date: (i32, u32, u32),
+        #[case] expect: (i32, u32, u32),
+    ) {
+        let calendar = BusinessCalendar::new();
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+        let expect = NaiveDate::from_ymd_opt(expect.0, expect.1, expect.2).unwrap();
+
+        assert_eq!(Some(expect), calendar.next_business_day(&date));
+    }
+
+    #[rstest]
+    #[case((2024, 5, 7), (2024, 5, 2))]
+    #[case((2024, 5, 6), (2024, 5, 2))]
+    #[case((2024, 9, 17), (2024, 9, 13))]
+    #[case((2025, 1, 14), (2025, 1, 10))]
+    fn 前営業日を導出できる(
+        #[case] date: (i32, u32, u32),
+        #[case] expect: (i32, u32, u32),
+    ) {
+        let calendar = BusinessCalendar::new();
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+        let expect = NaiveDate::from_ymd_opt(expect.0, expect.1, expect.2).unwrap();
+
+        assert_eq!(Some(expect), calendar.previous_business_day(&date));
+    }
+
+    #[rstest]
+    #[case((2024, 5, 1), (2024, 5, 31), 21)]
+    #[case((2024, 5, 3), (2024, 5, 6), 0)]
+    #[case((2024, 5, 7), (2024, 5, 7), 1)]
+    #[case((2024, 5, 31), (2024, 5, 1), 0)]
+    fn 期間内の営業日数を数えられる(
+        #[case] from: (i32, u32, u32),
+        #[case] until: (i32, u32, u32),
+        #[case] expect: usize,
+    ) {
+        let calendar = BusinessCalendar::new();
+        let from = NaiveDate::from_ymd_opt(from.0, from.1, from.2).unwrap();
+        let until = NaiveDate::from_ymd_opt(until.0, until.1, until.2).unwrap();
+
+        assert_eq!(expect, calendar.business_days_between(&from, &until));
+    }
+
+    #[rstest]
+    fn 週末を指定できる() {
+        let calendar = BusinessCalendar::with_weekend(&[JapaneseWeekday::Kin, JapaneseWeekday::Do]);
+        let thursday = NaiveDate::from_ymd_opt(2024, 6, 6).unwrap();
+        let sunday = NaiveDate::from_ymd_opt(2024, 6, 9).unwrap();
+
+        assert!(calendar.is_business_day(&sunday));
+        assert_eq!(Some(sunday), calendar.next_business_day(&thursday));
+    }
+
+    #[rstest]
+    fn 営業日がなければ導出できない() {
+        let calendar = BusinessCalendar::with_weekend(&[
+            JapaneseWeekday::Getsu,
+            JapaneseWeekday::Ka,
+            JapaneseWeekday::Sui,
+            JapaneseWeekday::Moku,
+            JapaneseWeekday::Kin,
+            JapaneseWeekday::Do,
+            JapaneseWeekday::Nichi,
+        ]);
+        let date = NaiveDate::from_ymd_opt(2024, 6, 6).unwrap();
+
+        assert!(calendar.next_business_day(&date).is_none());
+        assert!(calendar.previous_business_day(&date).is_none());
+        assert!(calendar.add_business_days(&date, 1).is_none());
+    }
+
+    #[rstest]
+    fn 毎日が休日であれば営業日を導出できない() {
+        let calendar = BusinessCalendar::new().with_holidays(EveryDay);
+        let date = NaiveDate::from_ymd_opt(2024, 6, 6).unwrap();
+
+        assert!(calendar.next_business_day(&date).is_none());
+        assert!(calendar.previous_business_day(&date).is_none());
+        assert!(calendar.sub_business_days(&date, 1).is_none());
+    }
+
+    #[rstest]
+    fn 独自の休日を営業日から除外できる() {
+        let holidays = HolidayCalendar::new()
+            .with_fixed_date("年末年始休暇", 12, 31)
+            .with_fixed_date("年末年始休暇", 1, 2)
+            .with_fixed_date("年末年始休暇", 1, 3);
+        let calendar = BusinessCalendar::new().with_holidays(&holidays);
+        let date = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
+
+        let expect = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        assert_eq!(Some(expect), calendar.next_business_day(&date));
+    }
+}
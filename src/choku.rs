@@ -0,0 +1,196 @@
+use crate::internal::{CHOKU, day_cycle_index};
+use crate::year::solar_month;
+use chrono::Datelike;
+
+use self::Choku::*;
+
+/// Twelve day officers (十二直)
+///
+/// 建 falls on the day whose earthly branch is the same as that of the month,
+/// and the others follow in order.
+/// The month changes at each 節 of the solar terms,
+/// so the day officer of the previous day is repeated on the day of 節入り.
+///
+/// You can convert between the order of the day officers,
+/// noting that it starts from `1` (建).
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Choku {
+    /// 建
+    Tatsu,
+    /// 除
+    Nozoku,
+    /// 満
+    Mitsu,
+    /// 平
+    Taira,
+    /// 定
+    Sadan,
+    /// 執
+    Toru,
+    /// 破
+    Yaburu,
+    /// 危
+    Ayabu,
+    /// 成
+    Naru,
+    /// 納
+    Osan,
+    /// 開
+    Hiraku,
+    /// 閉
+    Tozu,
+}
+
+impl Choku {
+    /// Generate from Datelike of chrono.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::Choku;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// assert_eq!(Choku::Tatsu, Choku::from_datelike(&date));
+    /// ```
+    pub fn from_datelike<T: Datelike>(date: &T) -> Self {
+        let (_, month) = solar_month(date);
+        // 寅 month is the 3rd of the earthly branches.
+        let index = (day_cycle_index(date) + 12 - (month + 2) % 12) % 12;
+
+        Choku::from_number(index + 1).unwrap()
+    }
+
+    /// Generate from name of day officer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Choku;
+    ///
+    /// assert_eq!(Some(Choku::Naru), Choku::from_name("成"));
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        CHOKU
+            .iter()
+            .position(|&(x, _)| x == name)
+            .and_then(|i| Choku::from_number(i + 1))
+    }
+
+    /// Generate from number of day officer.
+    /// The index starts from `1` (建).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Choku;
+    ///
+    /// assert!(Choku::from_number(12).is_some());
+    /// assert!(Choku::from_number(13).is_none());
+    /// ```
+    pub const fn from_number(number: usize) -> Option<Self> {
+        match number {
+            1 => Some(Tatsu),
+            2 => Some(Nozoku),
+            3 => Some(Mitsu),
+            4 => Some(Taira),
+            5 => Some(Sadan),
+            6 => Some(Toru),
+            7 => Some(Yaburu),
+            8 => Some(Ayabu),
+            9 => Some(Naru),
+            10 => Some(Osan),
+            11 => Some(Hiraku),
+            12 => Some(Tozu),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of day officer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Choku;
+    ///
+    /// assert_eq!("建", Choku::Tatsu.name());
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        CHOKU[self.to_number() - 1].0
+    }
+
+    /// Returns the reading of day officer in hiragana.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Choku;
+    ///
+    /// assert_eq!("さだん", Choku::Sadan.reading());
+    /// ```
+    pub const fn reading(&self) -> &'static str {
+        CHOKU[self.to_number() - 1].1
+    }
+
+    /// Returns the number of day officer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Choku;
+    ///
+    /// assert_eq!(12, Choku::Tozu.to_number());
+    /// ```
+    pub const fn to_number(&self) -> usize {
+        match self {
+            Tatsu => 1,
+            Nozoku => 2,
+            Mitsu => 3,
+            Taira => 4,
+            Sadan => 5,
+            Toru => 6,
+            Yaburu => 7,
+            Ayabu => 8,
+            Naru => 9,
+            Osan => 10,
+            Hiraku => 11,
+            Tozu => 12,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::NaiveDate;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case((2024, 1, 1), Tatsu)]
+    #[case((2024, 1, 2), Nozoku)]
+    #[case((2024, 1, 5), Sadan)]
+    #[case((2024, 1, 6), Sadan)]
+    #[case((2024, 1, 7), Toru)]
+    #[case((2024, 2, 3), Naru)]
+    #[case((2024, 2, 4), Naru)]
+    #[case((2024, 2, 5), Osan)]
+    fn 年月日から変換できる(#[case] date: (i32, u32, u32), #[case] expect: Choku) {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+
+        assert_eq!(expect, Choku::from_datelike(&date));
+    }
+
+    #[rstest]
+    fn 名前と読みと番号を相互に変換できる() {
+        for number in 1..=12 {
+            let choku = Choku::from_number(number).unwrap();
+
+            assert_eq!(number, choku.to_number());
+            assert_eq!(Some(choku), Choku::from_name(choku.name()));
+            assert!(!choku.reading().is_empty());
+        }
+        assert!(Choku::from_number(0).is_none());
+        assert!(Choku::from_name("大安").is_none());
+    }
+}
@@ -0,0 +1,433 @@
+use crate::day::{JapaneseHoliday, JapaneseWeekday};
+use crate::internal::to_naive_date;
+use crate::provider::{Holiday, HolidayProvider};
+
+use chrono::{Datelike, NaiveDate};
+
+/// Rule of custom holiday.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CustomHolidayRule {
+    /// The same month and day every year.
+    FixedDate { month: u32, day: u32 },
+    /// The nth weekday of the month every year, e.g. the second Monday of January.
+    NthWeekday {
+        month: u32,
+        nth: u32,
+        weekday: JapaneseWeekday,
+    },
+    /// The specified date only.
+    OneOff(NaiveDate),
+}
+
+impl CustomHolidayRule {
+    /// Returns the date is matched with the rule or not.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{CustomHolidayRule, JapaneseWeekday};
+    ///
+    /// let rule = CustomHolidayRule::NthWeekday {
+    ///     month: 6,
+    ///     nth: 3,
+    ///     weekday: JapaneseWeekday::Kin,
+    /// };
+    ///
+    /// assert!(rule.matches(&NaiveDate::from_ymd_opt(2024, 6, 21).unwrap()));
+    /// assert!(!rule.matches(&NaiveDate::from_ymd_opt(2024, 6, 14).unwrap()));
+    /// ```
+    pub fn matches<T: Datelike>(&self, date: &T) -> bool {
+        match *self {
+            CustomHolidayRule::FixedDate { month, day } => {
+                date.month() == month && date.day() == day
+            }
+            CustomHolidayRule::NthWeekday {
+                month,
+                nth,
+                weekday,
+            } => {
+                date.month() == month
+                    && (date.day() - 1) / 7 + 1 == nth
+                    && JapaneseWeekday::from_datelike(date) == weekday
+            }
+            CustomHolidayRule::OneOff(d) => {
+                (date.year(), date.month(), date.day()) == (d.year(), d.month(), d.day())
+            }
+        }
+    }
+}
+
+/// Custom holiday
+///
+/// A named holiday which is not defined by law, such as a company holiday.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomHoliday {
+    name: String,
+    rule: CustomHolidayRule,
+}
+
+impl CustomHoliday {
+    /// Generate from name and rule.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::{CustomHoliday, CustomHolidayRule};
+    ///
+    /// let holiday = CustomHoliday::new("創立記念日", CustomHolidayRule::FixedDate { month: 7, day: 1 });
+    ///
+    /// assert_eq!("創立記念日", holiday.name());
+    /// ```
+    pub fn new(name: &str, rule: CustomHolidayRule) -> Self {
+        Self {
+            name: name.to_string(),
+            rule,
+        }
+    }
+
+    /// Returns the name of custom holiday.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{CustomHoliday, CustomHolidayRule};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 8, 13).unwrap();
+    /// let holiday = CustomHoliday::new("夏季休暇", CustomHolidayRule::OneOff(date));
+    ///
+    /// assert_eq!("夏季休暇", holiday.name());
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the rule of custom holiday.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::{CustomHoliday, CustomHolidayRule};
+    ///
+    /// let rule = CustomHolidayRule::FixedDate { month: 7, day: 1 };
+    /// let holiday = CustomHoliday::new("創立記念日", rule);
+    ///
+    /// assert_eq!(rule, holiday.rule());
+    /// ```
+    pub const fn rule(&self) -> CustomHolidayRule {
+        self.rule
+    }
+}
+
+/// Holiday of [`HolidayCalendar`]
+///
+/// Either a Japanese national holiday or a custom holiday.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CalendarHoliday {
+    /// Japanese national holiday
+    National(JapaneseHoliday),
+    /// Custom holiday
+    Custom(CustomHoliday),
+}
+
+impl CalendarHoliday {
+    /// Returns the name of holiday.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::{CalendarHoliday, JapaneseHoliday};
+    ///
+    /// assert_eq!("元日", CalendarHoliday::National(JapaneseHoliday::NewYearsDay).name());
+    /// ```
+    pub fn name(&self) -> &str {
+        match self {
+            CalendarHoliday::National(h) => h.name(),
+            CalendarHoliday::Custom(h) => h.name(),
+        }
+    }
+}
+
+/// Holiday calendar
+///
+/// A calendar of custom holidays layered on Japanese national holidays.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use koyomi_rs::HolidayCalendar;
+///
+/// let calendar = HolidayCalendar::new()
+///     .with_fixed_date("年末休暇", 12, 30)
+///     .with_fixed_date("年末休暇", 12, 31)
+///     .with_fixed_date("年始休暇", 1, 2)
+///     .with_fixed_date("年始休暇", 1, 3);
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+/// assert_eq!(Some("年末休暇"), calendar.holiday_name(&date));
+///
+/// let date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+/// assert_eq!(Some("元日"), calendar.holiday_name(&date));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HolidayCalendar {
+    holidays: Vec<CustomHoliday>,
+}
+
+impl HolidayCalendar {
+    /// Returns the custom holiday of the date.
+    ///
+    /// When multiple custom holidays are matched, the first added one is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::HolidayCalendar;
+    ///
+    /// let calendar = HolidayCalendar::new().with_fixed_date("創立記念日", 7, 1);
+    /// let date = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+    ///
+    /// assert_eq!("創立記念日", calendar.custom_holiday(&date).unwrap().name());
+    /// ```
+    pub fn custom_holiday<T: Datelike>(&self, date: &T) -> Option<&CustomHoliday> {
+        self.holidays.iter().find(|h| h.rule.matches(date))
+    }
+
+    /// Returns the holiday of the date.
+    ///
+    /// Japanese national holidays take precedence over custom holidays.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{CalendarHoliday, HolidayCalendar, JapaneseHoliday};
+    ///
+    /// let calendar = HolidayCalendar::new().with_fixed_date("年始休暇", 1, 1);
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    ///
+    /// assert_eq!(
+    ///     Some(CalendarHoliday::National(JapaneseHoliday::NewYearsDay)),
+    ///     calendar.holiday(&date)
+    /// );
+    /// ```
+    pub fn holiday<T: Datelike>(&self, date: &T) -> Option<CalendarHoliday> {
+        JapaneseHoliday::holiday(date)
+            .map(CalendarHoliday::National)
+            .or_else(|| {
+                self.custom_holiday(date)
+                    .cloned()
+                    .map(CalendarHoliday::Custom)
+            })
+    }
+
+    /// Returns the name of holiday.
+    ///
+    /// Japanese national holidays take precedence over custom holidays.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::HolidayCalendar;
+    ///
+    /// let calendar = HolidayCalendar::new().with_fixed_date("年始休暇", 1, 1);
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    ///
+    /// assert_eq!(Some("元日"), calendar.holiday_name(&date));
+    /// ```
+    pub fn holiday_name<T: Datelike>(&self, date: &T) -> Option<&str> {
+        JapaneseHoliday::holiday(date)
+            .map(|h| h.name())
+            .or(self.custom_holiday(date).map(|h| h.name()))
+    }
+
+    /// Returns the holiday, including custom holidays, or not.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::HolidayCalendar;
+    ///
+    /// let calendar = HolidayCalendar::new().with_fixed_date("創立記念日", 7, 1);
+    ///
+    /// assert!(calendar.is_holiday(&NaiveDate::from_ymd_opt(2024, 7, 1).unwrap()));
+    /// assert!(calendar.is_holiday(&NaiveDate::from_ymd_opt(2024, 7, 15).unwrap()));
+    /// assert!(!calendar.is_holiday(&NaiveDate::from_ymd_opt(2024, 7, 2).unwrap()));
+    /// ```
+    pub fn is_holiday<T: Datelike>(&self, date: &T) -> bool {
+        JapaneseHoliday::holiday(date).is_some() || self.custom_holiday(date).is_some()
+    }
+
+    /// Generate a calendar without custom holidays.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::HolidayCalendar;
+    ///
+    /// let calendar = HolidayCalendar::new();
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    ///
+    /// assert!(calendar.is_holiday(&date));
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a custom holiday on the same month and day every year.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::HolidayCalendar;
+    ///
+    /// let calendar = HolidayCalendar::new().with_fixed_date("お盆休み", 8, 13);
+    ///
+    /// assert!(calendar.is_holiday(&NaiveDate::from_ymd_opt(2024, 8, 13).unwrap()));
+    /// assert!(calendar.is_holiday(&NaiveDate::from_ymd_opt(2025, 8, 13).unwrap()));
+    /// ```
+    pub fn with_fixed_date(self, name: &str, month: u32, day: u32) -> Self {
+        self.with_holiday(CustomHoliday::new(
+            name,
+            CustomHolidayRule::FixedDate { month, day },
+        ))
+    }
+
+    /// Add a custom holiday.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{CustomHoliday, CustomHolidayRule, HolidayCalendar};
+    ///
+    /// let holiday = CustomHoliday::new("創立記念日", CustomHolidayRule::FixedDate { month: 7, day: 1 });
+    /// let calendar = HolidayCalendar::new().with_holiday(holiday);
+    ///
+    /// assert!(calendar.is_holiday(&NaiveDate::from_ymd_opt(2024, 7, 1).unwrap()));
+    /// ```
+    pub fn with_holiday(mut self, holiday: CustomHoliday) -> Self {
+        self.holidays.push(holiday);
+        self
+    }
+
+    /// Add a custom holiday on the nth weekday of the month every year.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{HolidayCalendar, JapaneseWeekday};
+    ///
+    /// // The third Friday of June.
+    /// let calendar = HolidayCalendar::new().with_nth_weekday("社員旅行", 6, 3, JapaneseWeekday::Kin);
+    ///
+    /// assert!(calendar.is_holiday(&NaiveDate::from_ymd_opt(2024, 6, 21).unwrap()));
+    /// assert!(calendar.is_holiday(&NaiveDate::from_ymd_opt(2025, 6, 20).unwrap()));
+    /// ```
+    pub fn with_nth_weekday(
+        self,
+        name: &str,
+        month: u32,
+        nth: u32,
+        weekday: JapaneseWeekday,
+    ) -> Self {
+        self.with_holiday(CustomHoliday::new(
+            name,
+            CustomHolidayRule::NthWeekday {
+                month,
+                nth,
+                weekday,
+            },
+        ))
+    }
+
+    /// Add a custom holiday on the specified date only.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::HolidayCalendar;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 10, 1).unwrap();
+    /// let calendar = HolidayCalendar::new().with_one_off("50周年記念日", &date);
+    ///
+    /// assert!(calendar.is_holiday(&date));
+    /// assert!(!calendar.is_holiday(&NaiveDate::from_ymd_opt(2025, 10, 1).unwrap()));
+    /// ```
+    pub fn with_one_off<T: Datelike>(self, name: &str, date: &T) -> Self {
+        let date = to_naive_date(date);
+        self.with_holiday(CustomHoliday::new(name, CustomHolidayRule::OneOff(date)))
+    }
+}
+
+impl Holiday for CalendarHoliday {
+    fn name(&self) -> &str {
+        CalendarHoliday::name(self)
+    }
+}
+
+impl HolidayProvider for HolidayCalendar {
+    type Holiday = CalendarHoliday;
+
+    fn holiday(&self, date: &NaiveDate) -> Option<Self::Holiday> {
+        HolidayCalendar::holiday(self, date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn company_calendar() -> HolidayCalendar {
+        HolidayCalendar::new()
+            .with_fixed_date("年末年始休暇", 12, 29)
+            .with_fixed_date("年末年始休暇", 12, 30)
+            .with_fixed_date("年末年始休暇", 12, 31)
+            .with_fixed_date("年末年始休暇", 1, 2)
+            .with_fixed_date("年末年始休暇", 1, 3)
+            .with_fixed_date("創立記念日", 7, 1)
+            .with_nth_weekday("社員旅行", 6, 3, JapaneseWeekday::Kin)
+            .with_one_off("夏季休暇", &NaiveDate::from_ymd_opt(2024, 8, 13).unwrap())
+    }
+
+    #[rstest]
+    #[case((2024, 12, 29), "年末年始休暇")]
+    #[case((2025, 1, 1), "元日")]
+    #[case((2025, 1, 3), "年末年始休暇")]
+    #[case((2024, 7, 1), "創立記念日")]
+    #[case((2024, 6, 21), "社員旅行")]
+    #[case((2025, 6, 20), "社員旅行")]
+    #[case((2024, 8, 12), "振替休日")]
+    #[case((2024, 8, 13), "夏季休暇")]
+    fn 独自の休日を導出できる(#[case] ymd: (i32, u32, u32), #[case] expect: &str) {
+        let date = NaiveDate::from_ymd_opt(ymd.0, ymd.1, ymd.2).unwrap();
+        let calendar = company_calendar();
+
+        assert!(calendar.is_holiday(&date));
+        assert_eq!(Some(expect), calendar.holiday_name(&date));
+    }
+
+    #[rstest]
+    #[case((2024, 12, 28))]
+    #[case((2025, 1, 6))]
+    #[case((2024, 6, 14))]
+    #[case((2024, 6, 28))]
+    #[case((2025, 8, 13))]
+    fn 独自の休日でなければ導出されない(#[case] ymd: (i32, u32, u32)) {
+        let date = NaiveDate::from_ymd_opt(ymd.0, ymd.1, ymd.2).unwrap();
+        let calendar = company_calendar();
+
+        assert!(!calendar.is_holiday(&date));
+        assert!(calendar.holiday_name(&date).is_none());
+    }
+}
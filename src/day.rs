@@ -1,9 +1,13 @@
-use crate::internal::JAPANESE_WEEKDAY;
-use chrono::{Datelike, NaiveDate, Weekday};
+use crate::astronomy::{jst_from_jde, solar_longitude_jde};
+use crate::internal::{JAPANESE_WEEKDAY, to_naive_date};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Weekday};
 
 use self::JapaneseHoliday::*;
 use self::JapaneseWeekday::*;
 
+/// The year in which the law of national holidays was enacted.
+const ENACTED_YEAR: i32 = 1948;
+
 /// Japanese specific holidays.
 ///
 /// It calculated and determines holidays based on the law enacted in 1948.
@@ -63,6 +67,23 @@ pub enum JapaneseHoliday {
 }
 
 impl JapaneseHoliday {
+    /// Returns the time of autumnal equinox in JST.
+    ///
+    /// It's calculated from the apparent solar longitude.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseHoliday;
+    ///
+    /// let equinox = JapaneseHoliday::autumnal_equinox(2024).unwrap();
+    /// assert_eq!(NaiveDate::from_ymd_opt(2024, 9, 22).unwrap(), equinox.date());
+    /// ```
+    pub fn autumnal_equinox(year: i32) -> Option<NaiveDateTime> {
+        solar_longitude_jde(year, 180.0).and_then(jst_from_jde)
+    }
+
     /// [秋分の日](https://ja.wikipedia.org/wiki/秋分の日)
     ///
     /// # Example
@@ -94,7 +115,7 @@ impl JapaneseHoliday {
     /// ```
     pub fn childrens_day<T: Datelike>(date: &T) -> Option<Self> {
         match (date.year(), date.month(), date.day()) {
-            (1948.., 5, 5) => Some(ChildrensDay),
+            (ENACTED_YEAR.., 5, 5) => Some(ChildrensDay),
             _ => None,
         }
     }
@@ -111,10 +132,9 @@ impl JapaneseHoliday {
     /// assert!(JapaneseHoliday::citizens_holiday(&date).is_some());
     /// ```
     pub fn citizens_holiday<T: Datelike>(date: &T) -> Option<Self> {
-        let enforced = NaiveDate::from_ymd_opt(1985, 12, 27).unwrap();
-        let today = NaiveDate::from_ymd_opt(date.year(), date.month(), date.day()).unwrap();
+        let today = to_naive_date(date);
 
-        if today < enforced {
+        if !is_citizens_holiday_enforced(&today) {
             None
         } else {
             let yesterday = today
@@ -163,7 +183,7 @@ impl JapaneseHoliday {
     /// ```
     pub fn constitution_day<T: Datelike>(date: &T) -> Option<Self> {
         match (date.year(), date.month(), date.day()) {
-            (1948.., 5, 3) => Some(ConstitutionDay),
+            (ENACTED_YEAR.., 5, 3) => Some(ConstitutionDay),
             _ => None,
         }
     }
@@ -181,7 +201,7 @@ impl JapaneseHoliday {
     /// ```
     pub fn culture_day<T: Datelike>(date: &T) -> Option<Self> {
         match (date.year(), date.month(), date.day()) {
-            (1948.., 11, 3) => Some(CultureDay),
+            (ENACTED_YEAR.., 11, 3) => Some(CultureDay),
             _ => None,
         }
     }
@@ -242,6 +262,96 @@ impl JapaneseHoliday {
             .or(JapaneseHoliday::citizens_holiday(date))
     }
 
+    /// Returns the holidays between the specified dates in date order.
+    /// Both `from` and `until` are included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseHoliday;
+    ///
+    /// let from = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+    /// let until = NaiveDate::from_ymd_opt(2024, 5, 31).unwrap();
+    /// let holidays = JapaneseHoliday::holidays_between(&from, &until);
+    ///
+    /// assert_eq!(4, holidays.len());
+    /// assert_eq!(
+    ///     (NaiveDate::from_ymd_opt(2024, 5, 6).unwrap(), JapaneseHoliday::SubstituteDay),
+    ///     holidays[3]
+    /// );
+    /// ```
+    pub fn holidays_between<T: Datelike>(from: &T, until: &T) -> Vec<(NaiveDate, Self)> {
+        let from = to_naive_date(from);
+        let until = to_naive_date(until);
+
+        (from.year()..=until.year())
+            .flat_map(|y| {
+                let holidays = JapaneseHoliday::holidays_in_year(y);
+                // Only the first and the last year can include days out of the period.
+                match (y == from.year(), y == until.year()) {
+                    (false, false) => holidays,
+                    _ => holidays
+                        .into_iter()
+                        .filter(|(d, _)| from <= *d && *d <= until)
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the holidays of the specified year in date order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseHoliday;
+    ///
+    /// let holidays = JapaneseHoliday::holidays_in_year(2024);
+    ///
+    /// assert_eq!(21, holidays.len());
+    /// assert_eq!(
+    ///     (NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(), JapaneseHoliday::NewYearsDay),
+    ///     holidays[0]
+    /// );
+    /// ```
+    pub fn holidays_in_year(year: i32) -> Vec<(NaiveDate, Self)> {
+        let mut holidays: Vec<(NaiveDate, Self)> = holiday_candidates(year)
+            .into_iter()
+            .filter_map(|d| JapaneseHoliday::holiday_without_substitute(&d).map(|h| (d, h)))
+            .collect();
+        holidays.sort_by_key(|(d, _)| *d);
+        holidays.dedup_by_key(|(d, _)| *d);
+
+        // Substitute holidays and citizens' holidays are derived from the holidays above,
+        // in the same way as `substitute_holiday` and `citizens_holiday`.
+        let is_holiday = |d: &NaiveDate| holidays.binary_search_by_key(d, |(h, _)| *h).is_ok();
+        let mut derived = Vec::new();
+        for (date, _) in holidays.iter().filter(|(d, _)| d.weekday() == Weekday::Sun) {
+            // The first day which is not a holiday after the Sunday.
+            let mut next = date.succ_opt();
+            while let Some(d) = next.filter(is_holiday) {
+                next = d.succ_opt();
+            }
+            if let Some(d) = next.filter(is_substitute_holiday_enforced) {
+                derived.push((d, SubstituteDay));
+            }
+        }
+        for ((a, _), (b, _)) in holidays.iter().zip(holidays.iter().skip(1)) {
+            let between = a.succ_opt().filter(|d| d.succ_opt() == Some(*b));
+            if let Some(d) = between.filter(is_citizens_holiday_enforced)
+                && !derived.contains(&(d, SubstituteDay))
+            {
+                derived.push((d, CitizensHoliday));
+            }
+        }
+
+        holidays.extend(derived);
+        holidays.sort_by_key(|(d, _)| *d);
+        holidays
+    }
+
     /// [皇室慶弔行事に伴う休日](https://ja.wikipedia.org/wiki/皇室慶弔行事に伴う休日)
     ///
     /// # Example
@@ -278,7 +388,7 @@ impl JapaneseHoliday {
     /// ```
     pub fn labor_thanksgiving_day<T: Datelike>(date: &T) -> Option<Self> {
         match (date.year(), date.month(), date.day()) {
-            (1948.., 11, 23) => Some(LaborThanksgivingDay),
+            (ENACTED_YEAR.., 11, 23) => Some(LaborThanksgivingDay),
             _ => None,
         }
     }
@@ -299,7 +409,7 @@ impl JapaneseHoliday {
             (2020, 7, 23) => Some(MarineDay),
             (2021, 7, 22) => Some(MarineDay),
             (1996..=2002, 7, 20) => Some(MarineDay),
-            (2003.., 7, _) if happy_monday_third(date) => Some(MarineDay),
+            (2003..=2019 | 2022.., 7, _) if happy_monday_third(date) => Some(MarineDay),
             _ => None,
         }
     }
@@ -319,7 +429,7 @@ impl JapaneseHoliday {
         match (date.year(), date.month(), date.day()) {
             (2020, 8, 10) => Some(MountainDay),
             (2021, 8, 8) => Some(MountainDay),
-            (2016.., 8, 11) => Some(MountainDay),
+            (2016..=2019 | 2022.., 8, 11) => Some(MountainDay),
             _ => None,
         }
     }
@@ -400,6 +510,32 @@ impl JapaneseHoliday {
         }
     }
 
+    /// Returns the first holiday after the specified date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseHoliday;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 10, 17).unwrap();
+    ///
+    /// assert_eq!(
+    ///     Some((NaiveDate::from_ymd_opt(2024, 11, 3).unwrap(), JapaneseHoliday::CultureDay)),
+    ///     JapaneseHoliday::next_after(&date)
+    /// );
+    /// ```
+    pub fn next_after<T: Datelike>(date: &T) -> Option<(NaiveDate, Self)> {
+        let date = to_naive_date(date);
+
+        // No holiday can be found before the year in which the law was enacted.
+        (date.year().max(ENACTED_YEAR)..=NaiveDate::MAX.year()).find_map(|y| {
+            JapaneseHoliday::holidays_in_year(y)
+                .into_iter()
+                .find(|(d, _)| *d > date)
+        })
+    }
+
     /// [体育の日](https://ja.wikipedia.org/wiki/スポーツの日_(日本))
     ///
     /// # Example
@@ -419,6 +555,32 @@ impl JapaneseHoliday {
         }
     }
 
+    /// Returns the last holiday before the specified date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseHoliday;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 11, 3).unwrap();
+    ///
+    /// assert_eq!(
+    ///     Some((NaiveDate::from_ymd_opt(2024, 10, 14).unwrap(), JapaneseHoliday::SportsDay)),
+    ///     JapaneseHoliday::previous_before(&date)
+    /// );
+    /// ```
+    pub fn previous_before<T: Datelike>(date: &T) -> Option<(NaiveDate, Self)> {
+        let date = to_naive_date(date);
+
+        (ENACTED_YEAR..=date.year()).rev().find_map(|y| {
+            JapaneseHoliday::holidays_in_year(y)
+                .into_iter()
+                .rev()
+                .find(|(d, _)| *d < date)
+        })
+    }
+
     /// [敬老の日](https://ja.wikipedia.org/wiki/敬老の日)
     ///
     /// # Example
@@ -471,7 +633,7 @@ impl JapaneseHoliday {
         match (date.year(), date.month(), date.day()) {
             (2020, 7, 24) => Some(SportsDay),
             (2021, 7, 23) => Some(SportsDay),
-            (2020.., 10, _) if happy_monday_second(date) => Some(SportsDay),
+            (2022.., 10, _) if happy_monday_second(date) => Some(SportsDay),
             _ => None,
         }
     }
@@ -488,10 +650,9 @@ impl JapaneseHoliday {
     /// assert!(JapaneseHoliday::substitute_holiday(&date).is_some());
     /// ```
     pub fn substitute_holiday<T: Datelike>(date: &T) -> Option<Self> {
-        let enforced = NaiveDate::from_ymd_opt(1973, 4, 30).unwrap();
-        let today = NaiveDate::from_ymd_opt(date.year(), date.month(), date.day()).unwrap();
+        let today = to_naive_date(date);
 
-        if today < enforced || date.year() <= 1973 {
+        if !is_substitute_holiday_enforced(&today) {
             None
         } else {
             today
@@ -504,6 +665,55 @@ impl JapaneseHoliday {
         }
     }
 
+    /// Returns an iterator of the holidays after the specified date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseHoliday;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 11, 1).unwrap();
+    /// let holidays: Vec<_> = JapaneseHoliday::upcoming(&date).take(3).collect();
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         (NaiveDate::from_ymd_opt(2024, 11, 3).unwrap(), JapaneseHoliday::CultureDay),
+    ///         (NaiveDate::from_ymd_opt(2024, 11, 4).unwrap(), JapaneseHoliday::SubstituteDay),
+    ///         (NaiveDate::from_ymd_opt(2024, 11, 23).unwrap(), JapaneseHoliday::LaborThanksgivingDay),
+    ///     ],
+    ///     holidays
+    /// );
+    /// ```
+    pub fn upcoming<T: Datelike>(date: &T) -> UpcomingHolidays {
+        let date = to_naive_date(date);
+        let year = date.year().max(ENACTED_YEAR);
+        let holidays = JapaneseHoliday::holidays_in_year(year);
+
+        UpcomingHolidays {
+            index: holidays.partition_point(|(d, _)| *d <= date),
+            holidays,
+            year,
+        }
+    }
+
+    /// Returns the time of vernal equinox in JST.
+    ///
+    /// It's calculated from the apparent solar longitude.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseHoliday;
+    ///
+    /// let equinox = JapaneseHoliday::vernal_equinox(2024).unwrap();
+    /// assert_eq!(NaiveDate::from_ymd_opt(2024, 3, 20).unwrap(), equinox.date());
+    /// ```
+    pub fn vernal_equinox(year: i32) -> Option<NaiveDateTime> {
+        solar_longitude_jde(year, 0.0).and_then(jst_from_jde)
+    }
+
     /// [春分の日](https://ja.wikipedia.org/wiki/春分の日)
     ///
     /// # Example
@@ -544,6 +754,35 @@ impl JapaneseHoliday {
     }
 }
 
+/// Iterator of upcoming holidays.
+///
+/// It's created by [`JapaneseHoliday::upcoming`] and yields holidays in date order.
+#[derive(Debug)]
+pub struct UpcomingHolidays {
+    holidays: Vec<(NaiveDate, JapaneseHoliday)>,
+    index: usize,
+    year: i32,
+}
+
+impl Iterator for UpcomingHolidays {
+    type Item = (NaiveDate, JapaneseHoliday);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // The holidays of the next year are calculated only after those of the current year.
+        while self.index == self.holidays.len() {
+            if self.year == NaiveDate::MAX.year() {
+                return None;
+            }
+            self.year += 1;
+            self.holidays = JapaneseHoliday::holidays_in_year(self.year);
+            self.index = 0;
+        }
+
+        self.index += 1;
+        Some(self.holidays[self.index - 1])
+    }
+}
+
 /// Japanese weekdays.
 ///
 /// It derives the names of the days of the week in Japanese.
@@ -663,34 +902,106 @@ impl JapaneseWeekday {
     }
 }
 
+/// The first year of [`EQUINOX_DAYS`].
+const EQUINOX_DAYS_SINCE: i32 = 1949;
+
+/// The days of vernal equinox in March and autumnal equinox in September
+/// from 1949 to 2150, precomputed with [`JapaneseHoliday::vernal_equinox`]
+/// and [`JapaneseHoliday::autumnal_equinox`].
+#[rustfmt::skip]
+const EQUINOX_DAYS: [(u32, u32); 202] = [
+    (21, 23), (21, 23), (21, 24), (21, 23), (21, 23), (21, 23), (21, 24), (21, 23), (21, 23), (21, 23),
+    (21, 24), (20, 23), (21, 23), (21, 23), (21, 24), (20, 23), (21, 23), (21, 23), (21, 24), (20, 23),
+    (21, 23), (21, 23), (21, 24), (20, 23), (21, 23), (21, 23), (21, 24), (20, 23), (21, 23), (21, 23),
+    (21, 24), (20, 23), (21, 23), (21, 23), (21, 23), (20, 23), (21, 23), (21, 23), (21, 23), (20, 23),
+    (21, 23), (21, 23), (21, 23), (20, 23), (20, 23), (21, 23), (21, 23), (20, 23), (20, 23), (21, 23),
+    (21, 23), (20, 23), (20, 23), (21, 23), (21, 23), (20, 23), (20, 23), (21, 23), (21, 23), (20, 23),
+    (20, 23), (21, 23), (21, 23), (20, 22), (20, 23), (21, 23), (21, 23), (20, 22), (20, 23), (21, 23),
+    (21, 23), (20, 22), (20, 23), (21, 23), (21, 23), (20, 22), (20, 23), (20, 23), (21, 23), (20, 22),
+    (20, 23), (20, 23), (21, 23), (20, 22), (20, 23), (20, 23), (21, 23), (20, 22), (20, 23), (20, 23),
+    (21, 23), (20, 22), (20, 23), (20, 23), (21, 23), (20, 22), (20, 22), (20, 23), (21, 23), (20, 22),
+    (20, 22), (20, 23), (21, 23), (20, 22), (20, 22), (20, 23), (21, 23), (20, 22), (20, 22), (20, 23),
+    (20, 23), (20, 22), (20, 22), (20, 23), (20, 23), (20, 22), (20, 22), (20, 23), (20, 23), (20, 22),
+    (20, 22), (20, 23), (20, 23), (20, 22), (20, 22), (20, 23), (20, 23), (20, 22), (20, 22), (20, 22),
+    (20, 23), (20, 22), (20, 22), (20, 22), (20, 23), (20, 22), (20, 22), (20, 22), (20, 23), (20, 22),
+    (20, 22), (20, 22), (20, 23), (19, 22), (20, 22), (20, 22), (20, 23), (19, 22), (20, 22), (20, 22),
+    (20, 23), (20, 23), (21, 23), (21, 23), (21, 24), (20, 23), (21, 23), (21, 23), (21, 23), (20, 23),
+    (21, 23), (21, 23), (21, 23), (20, 23), (21, 23), (21, 23), (21, 23), (20, 23), (21, 23), (21, 23),
+    (21, 23), (20, 23), (21, 23), (21, 23), (21, 23), (20, 23), (20, 23), (21, 23), (21, 23), (20, 23),
+    (20, 23), (21, 23), (21, 23), (20, 23), (20, 23), (21, 23), (21, 23), (20, 23), (20, 23), (21, 23),
+    (21, 23), (20, 22), (20, 23), (21, 23), (21, 23), (20, 22), (20, 23), (21, 23), (21, 23), (20, 22),
+    (20, 23), (21, 23),
+];
+
+fn autumnal_equinox_date(year: i32) -> Option<NaiveDate> {
+    match equinox_days(year) {
+        Some((_, day)) => NaiveDate::from_ymd_opt(year, 9, day),
+        None => JapaneseHoliday::autumnal_equinox(year).map(|e| e.date()),
+    }
+}
+
 fn autumnal_equinox_day<T: Datelike>(date: &T) -> bool {
-    date.day() == equinox_day(23.2488, date.year())
+    autumnal_equinox_date(date.year()).is_some_and(|e| is_same_date(&e, date))
 }
 
-fn vernal_equinox_day<T: Datelike>(date: &T) -> bool {
-    date.day() == equinox_day(20.8431, date.year())
+fn equinox_days(year: i32) -> Option<(u32, u32)> {
+    usize::try_from(year - EQUINOX_DAYS_SINCE)
+        .ok()
+        .and_then(|i| EQUINOX_DAYS.get(i))
+        .copied()
 }
 
-fn equinox_day(equinox: f64, year: i32) -> u32 {
-    let x = (year - 1980) as f64;
-    let y = ((0.242194_f64 * x) + equinox).floor() as i32;
-    let z = (x / 4.0_f64).floor() as i32;
+fn vernal_equinox_date(year: i32) -> Option<NaiveDate> {
+    match equinox_days(year) {
+        Some((day, _)) => NaiveDate::from_ymd_opt(year, 3, day),
+        None => JapaneseHoliday::vernal_equinox(year).map(|e| e.date()),
+    }
+}
 
-    (y - z).abs() as u32
+fn vernal_equinox_day<T: Datelike>(date: &T) -> bool {
+    vernal_equinox_date(date.year()).is_some_and(|e| is_same_date(&e, date))
+}
+
+fn holiday_candidates(year: i32) -> Vec<NaiveDate> {
+    #[rustfmt::skip]
+    const FIXED_DATES: [(u32, u32); 26] = [
+        (1, 1), (1, 15), (2, 11), (2, 23), (2, 24), (4, 10), (4, 29), (5, 1), (5, 3),
+        (5, 4), (5, 5), (6, 9), (7, 20), (7, 22), (7, 23), (7, 24), (8, 8), (8, 10),
+        (8, 11), (9, 15), (10, 10), (10, 22), (11, 3), (11, 12), (11, 23), (12, 23),
+    ];
+    const HAPPY_MONDAYS: [(u32, u8); 4] = [(1, 2), (7, 3), (9, 3), (10, 2)];
+
+    let fixed = FIXED_DATES
+        .iter()
+        .filter_map(|&(m, d)| NaiveDate::from_ymd_opt(year, m, d));
+    let happy_mondays = HAPPY_MONDAYS
+        .iter()
+        .filter_map(|&(m, n)| NaiveDate::from_weekday_of_month_opt(year, m, Weekday::Mon, n));
+    let equinoxes = [vernal_equinox_date(year), autumnal_equinox_date(year)]
+        .into_iter()
+        .flatten();
+
+    fixed.chain(happy_mondays).chain(equinoxes).collect()
 }
 
 fn happy_monday_second<T: Datelike>(date: &T) -> bool {
-    match (date.weekday(), date.day()) {
-        (Weekday::Mon, 8..=14) => true,
-        _ => false,
-    }
+    matches!((date.weekday(), date.day()), (Weekday::Mon, 8..=14))
 }
 
 fn happy_monday_third<T: Datelike>(date: &T) -> bool {
-    match (date.weekday(), date.day()) {
-        (Weekday::Mon, 15..=21) => true,
-        _ => false,
-    }
+    matches!((date.weekday(), date.day()), (Weekday::Mon, 15..=21))
+}
+
+fn is_citizens_holiday_enforced(date: &NaiveDate) -> bool {
+    *date >= NaiveDate::from_ymd_opt(1985, 12, 27).unwrap()
+}
+
+fn is_same_date<T: Datelike>(a: &NaiveDate, b: &T) -> bool {
+    (a.year(), a.month(), a.day()) == (b.year(), b.month(), b.day())
+}
+
+fn is_substitute_holiday_enforced(date: &NaiveDate) -> bool {
+    *date >= NaiveDate::from_ymd_opt(1973, 4, 30).unwrap() && date.year() > 1973
 }
 
 #[cfg(test)]
@@ -873,6 +1184,50 @@ mod vernal_equinox_day_tests {
             JapaneseHoliday::vernal_equinox_day(&date)
         );
     }
+
+    #[rstest]
+    #[case(2100, 20)]
+    #[case(2101, 21)]
+    #[case(2200, 21)]
+    fn 天文計算により2100年以降も春分の日を導出できる(
+        #[case] y: i32,
+        #[case] d: u32,
+    ) {
+        let date = NaiveDate::from_ymd_opt(y, 3, d).unwrap();
+        assert_eq!(
+            Some(VernalEquinoxDay),
+            JapaneseHoliday::vernal_equinox_day(&date)
+        );
+    }
+
+    #[rstest]
+    fn 春分の時刻を導出できる() {
+        let expect = NaiveDate::from_ymd_opt(2024, 3, 20)
+            .unwrap()
+            .and_hms_opt(12, 6, 0)
+            .unwrap();
+        let equinox = JapaneseHoliday::vernal_equinox(2024).unwrap();
+
+        assert!((equinox - expect).num_seconds().abs() < 60);
+    }
+
+    #[rstest]
+    fn 春分日と秋分日の表は天文計算と一致する() {
+        for year in super::EQUINOX_DAYS_SINCE..=2150 {
+            assert_eq!(
+                JapaneseHoliday::vernal_equinox(year).map(|e| e.date()),
+                super::vernal_equinox_date(year),
+                "{}",
+                year
+            );
+            assert_eq!(
+                JapaneseHoliday::autumnal_equinox(year).map(|e| e.date()),
+                super::autumnal_equinox_date(year),
+                "{}",
+                year
+            );
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1018,6 +1373,18 @@ mod marine_day_tests {
         assert_eq!(Some(MarineDay), JapaneseHoliday::marine_day(&date));
     }
 
+    #[rstest]
+    #[case(2020, 7, 20)]
+    #[case(2021, 7, 19)]
+    fn 東京オリンピックの特例がある年は7月の第3月曜が海の日ではない(
+        #[case] y: i32,
+        #[case] m: u32,
+        #[case] d: u32,
+    ) {
+        let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(None, JapaneseHoliday::marine_day(&date));
+    }
+
     #[rstest]
     fn 東京オリンピック以降は7月の第3月曜が海の日である() {
         let date = NaiveDate::from_ymd_opt(2022, 7, 18).unwrap();
@@ -1058,6 +1425,16 @@ mod mountain_day_tests {
         assert_eq!(Some(MountainDay), JapaneseHoliday::mountain_day(&date));
     }
 
+    #[rstest]
+    #[case(2020)]
+    #[case(2021)]
+    fn 東京オリンピックの特例がある年は8月11日が山の日ではない(
+        #[case] y: i32,
+    ) {
+        let date = NaiveDate::from_ymd_opt(y, 8, 11).unwrap();
+        assert_eq!(None, JapaneseHoliday::mountain_day(&date));
+    }
+
     #[rstest]
     fn 東京オリンピック以降は8月11日が山の日である() {
         let date = NaiveDate::from_ymd_opt(2022, 8, 11).unwrap();
@@ -1128,6 +1505,31 @@ mod autumnal_equinox_day_tests {
             JapaneseHoliday::autumnal_equinox_day(&date)
         );
     }
+
+    #[rstest]
+    #[case(2100, 23)]
+    #[case(2150, 23)]
+    fn 天文計算により2100年以降も秋分の日を導出できる(
+        #[case] y: i32,
+        #[case] d: u32,
+    ) {
+        let date = NaiveDate::from_ymd_opt(y, 9, d).unwrap();
+        assert_eq!(
+            Some(AutumnalEquinoxDay),
+            JapaneseHoliday::autumnal_equinox_day(&date)
+        );
+    }
+
+    #[rstest]
+    fn 秋分の時刻を導出できる() {
+        let expect = NaiveDate::from_ymd_opt(2024, 9, 22)
+            .unwrap()
+            .and_hms_opt(21, 44, 0)
+            .unwrap();
+        let equinox = JapaneseHoliday::autumnal_equinox(2024).unwrap();
+
+        assert!((equinox - expect).num_seconds().abs() < 60);
+    }
 }
 
 #[cfg(test)]
@@ -1188,6 +1590,18 @@ mod sports_day_tests {
         assert_eq!(Some(SportsDay), JapaneseHoliday::sports_day(&date));
     }
 
+    #[rstest]
+    #[case(2020, 10, 12)]
+    #[case(2021, 10, 11)]
+    fn 東京オリンピックの特例がある年は10月の第2月曜がスポーツの日ではない(
+        #[case] y: i32,
+        #[case] m: u32,
+        #[case] d: u32,
+    ) {
+        let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(None, JapaneseHoliday::sports_day(&date));
+    }
+
     #[rstest]
     fn 東京オリンピック以降は10月の第2月曜がスポーツの日である() {
         let date = NaiveDate::from_ymd_opt(2022, 10, 10).unwrap();
@@ -1444,3 +1858,262 @@ mod japanese_weekday_tests {
         assert_eq!(expect, weekday.number());
     }
 }
+
+#[cfg(test)]
+mod holidays_in_year_tests {
+    use super::JapaneseHoliday;
+    use super::JapaneseHoliday::*;
+    use chrono::{Datelike, NaiveDate};
+    use rstest::*;
+
+    #[rstest]
+    fn 年間の祝日を日付順に列挙できる() {
+        let holidays: Vec<_> = JapaneseHoliday::holidays_in_year(2024)
+            .into_iter()
+            .map(|(d, h)| (d.month(), d.day(), h))
+            .collect();
+
+        assert_eq!(
+            vec![
+                (1, 1, NewYearsDay),
+                (1, 8, ComingOfAgeDay),
+                (2, 11, NationalFoundationDay),
+                (2, 12, SubstituteDay),
+                (2, 23, EmperorsBirthday),
+                (3, 20, VernalEquinoxDay),
+                (4, 29, ShowaDay),
+                (5, 3, ConstitutionDay),
+                (5, 4, GreenDay),
+                (5, 5, ChildrensDay),
+                (5, 6, SubstituteDay),
+                (7, 15, MarineDay),
+                (8, 11, MountainDay),
+                (8, 12, SubstituteDay),
+                (9, 16, RespectForTheAgeDay),
+                (9, 22, AutumnalEquinoxDay),
+                (9, 23, SubstituteDay),
+                (10, 14, SportsDay),
+                (11, 3, CultureDay),
+                (11, 4, SubstituteDay),
+                (11, 23, LaborThanksgivingDay),
+            ],
+            holidays
+        );
+    }
+
+    #[rstest]
+    #[case(2019, vec![
+        (1, 1, NewYearsDay),
+        (1, 14, ComingOfAgeDay),
+        (2, 11, NationalFoundationDay),
+        (3, 21, VernalEquinoxDay),
+        (4, 29, ShowaDay),
+        (4, 30, CitizensHoliday),
+        (5, 1, EnthronmentOfEmperorReiwa),
+        (5, 2, CitizensHoliday),
+        (5, 3, ConstitutionDay),
+        (5, 4, GreenDay),
+        (5, 5, ChildrensDay),
+        (5, 6, SubstituteDay),
+        (7, 15, MarineDay),
+        (8, 11, MountainDay),
+        (8, 12, SubstituteDay),
+        (9, 16, RespectForTheAgeDay),
+        (9, 23, AutumnalEquinoxDay),
+        (10, 14, PhysicalEducationDay),
+        (10, 22, EnthronmentCeremonyOfEmperorReiwa),
+        (11, 3, CultureDay),
+        (11, 4, SubstituteDay),
+        (11, 23, LaborThanksgivingDay),
+    ])]
+    #[case(2020, vec![
+        (1, 1, NewYearsDay),
+        (1, 13, ComingOfAgeDay),
+        (2, 11, NationalFoundationDay),
+        (2, 23, EmperorsBirthday),
+        (2, 24, SubstituteDay),
+        (3, 20, VernalEquinoxDay),
+        (4, 29, ShowaDay),
+        (5, 3, ConstitutionDay),
+        (5, 4, GreenDay),
+        (5, 5, ChildrensDay),
+        (5, 6, SubstituteDay),
+        (7, 23, MarineDay),
+        (7, 24, SportsDay),
+        (8, 10, MountainDay),
+        (9, 21, RespectForTheAgeDay),
+        (9, 22, AutumnalEquinoxDay),
+        (11, 3, CultureDay),
+        (11, 23, LaborThanksgivingDay),
+    ])]
+    #[case(2021, vec![
+        (1, 1, NewYearsDay),
+        (1, 11, ComingOfAgeDay),
+        (2, 11, NationalFoundationDay),
+        (2, 23, EmperorsBirthday),
+        (3, 20, VernalEquinoxDay),
+        (4, 29, ShowaDay),
+        (5, 3, ConstitutionDay),
+        (5, 4, GreenDay),
+        (5, 5, ChildrensDay),
+        (7, 22, MarineDay),
+        (7, 23, SportsDay),
+        (8, 8, MountainDay),
+        (8, 9, SubstituteDay),
+        (9, 20, RespectForTheAgeDay),
+        (9, 23, AutumnalEquinoxDay),
+        (11, 3, CultureDay),
+        (11, 23, LaborThanksgivingDay),
+    ])]
+    fn 特例のある年の祝日を列挙できる(
+        #[case] year: i32,
+        #[case] expect: Vec<(u32, u32, JapaneseHoliday)>,
+    ) {
+        let holidays: Vec<_> = JapaneseHoliday::holidays_in_year(year)
+            .into_iter()
+            .map(|(d, h)| (d.month(), d.day(), h))
+            .collect();
+
+        assert_eq!(expect, holidays);
+    }
+
+    #[rstest]
+    #[case(2019, 4, 30, CitizensHoliday)]
+    #[case(2019, 5, 2, CitizensHoliday)]
+    #[case(2026, 9, 22, CitizensHoliday)]
+    #[case(2008, 5, 6, SubstituteDay)]
+    #[case(1959, 4, 10, WeddingCeremonyOfPrinceAkihito)]
+    fn 振替休日や国民の休日も列挙される(
+        #[case] y: i32,
+        #[case] m: u32,
+        #[case] d: u32,
+        #[case] expect: JapaneseHoliday,
+    ) {
+        let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert!(JapaneseHoliday::holidays_in_year(y).contains(&(date, expect)));
+    }
+
+    #[rstest]
+    fn 各日付の判定結果と一致する() {
+        for year in 1945..=2100 {
+            let expect: Vec<_> = NaiveDate::from_ymd_opt(year, 1, 1)
+                .unwrap()
+                .iter_days()
+                .take_while(|d| d.year() == year)
+                .filter_map(|d| JapaneseHoliday::holiday(&d).map(|h| (d, h)))
+                .collect();
+
+            assert_eq!(expect, JapaneseHoliday::holidays_in_year(year), "{}", year);
+        }
+    }
+
+    #[rstest]
+    fn 期間内の祝日を列挙できる() {
+        let from = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+        let until = NaiveDate::from_ymd_opt(2025, 1, 31).unwrap();
+
+        assert_eq!(
+            vec![
+                (NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(), NewYearsDay),
+                (
+                    NaiveDate::from_ymd_opt(2025, 1, 13).unwrap(),
+                    ComingOfAgeDay
+                ),
+            ],
+            JapaneseHoliday::holidays_between(&from, &until)
+        );
+        assert!(JapaneseHoliday::holidays_between(&until, &from).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod next_holiday_tests {
+    use super::JapaneseHoliday;
+    use super::JapaneseHoliday::*;
+    use chrono::NaiveDate;
+    use rstest::*;
+
+    #[rstest]
+    #[case((2024, 10, 17), (2024, 11, 3), CultureDay)]
+    #[case((2024, 11, 3), (2024, 11, 4), SubstituteDay)]
+    #[case((2024, 12, 31), (2025, 1, 1), NewYearsDay)]
+    #[case((2026, 9, 21), (2026, 9, 22), CitizensHoliday)]
+    #[case((1900, 1, 1), (1948, 5, 3), ConstitutionDay)]
+    #[case((1, 1, 1), (1948, 5, 3), ConstitutionDay)]
+    fn 次の祝日を導出できる(
+        #[case] date: (i32, u32, u32),
+        #[case] expect: (i32, u32, u32),
+        #[case] holiday: JapaneseHoliday,
+    ) {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+        let expect = NaiveDate::from_ymd_opt(expect.0, expect.1, expect.2).unwrap();
+
+        assert_eq!(Some((expect, holiday)), JapaneseHoliday::next_after(&date));
+    }
+
+    #[rstest]
+    #[case((2024, 11, 4), (2024, 11, 3), CultureDay)]
+    #[case((2025, 1, 1), (2024, 11, 23), LaborThanksgivingDay)]
+    #[case((2019, 5, 3), (2019, 5, 2), CitizensHoliday)]
+    fn 前の祝日を導出できる(
+        #[case] date: (i32, u32, u32),
+        #[case] expect: (i32, u32, u32),
+        #[case] holiday: JapaneseHoliday,
+    ) {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+        let expect = NaiveDate::from_ymd_opt(expect.0, expect.1, expect.2).unwrap();
+
+        assert_eq!(
+            Some((expect, holiday)),
+            JapaneseHoliday::previous_before(&date)
+        );
+    }
+
+    #[rstest]
+    fn 祝日法の施行以前に祝日は存在しない() {
+        let date = NaiveDate::from_ymd_opt(1948, 5, 3).unwrap();
+        assert!(JapaneseHoliday::previous_before(&date).is_none());
+    }
+
+    #[rstest]
+    fn 今後の祝日を順に列挙できる() {
+        let date = NaiveDate::from_ymd_opt(2019, 4, 28).unwrap();
+        let holidays: Vec<_> = JapaneseHoliday::upcoming(&date)
+            .take(7)
+            .map(|(_, h)| h)
+            .collect();
+
+        assert_eq!(
+            vec![
+                ShowaDay,
+                CitizensHoliday,
+                EnthronmentOfEmperorReiwa,
+                CitizensHoliday,
+                ConstitutionDay,
+                GreenDay,
+                ChildrensDay,
+            ],
+            holidays
+        );
+    }
+
+    #[rstest]
+    #[case((2024, 12, 20))]
+    #[case((1900, 1, 1))]
+    #[case((1, 1, 1))]
+    fn 今後の祝日は次の祝日の導出と一致する(#[case] date: (i32, u32, u32)) {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+        let expect: Vec<_> = std::iter::successors(JapaneseHoliday::next_after(&date), |(d, _)| {
+            JapaneseHoliday::next_after(d)
+        })
+        .take(30)
+        .collect();
+
+        assert_eq!(
+            expect,
+            JapaneseHoliday::upcoming(&date)
+                .take(30)
+                .collect::<Vec<_>>()
+        );
+    }
+}
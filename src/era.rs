@@ -1,16 +1,31 @@
+use std::error::Error;
+use std::fmt;
+use std::sync::RwLock;
+
 use crate::internal::InternalDate;
-use chrono::Datelike;
+use crate::numerals::parse_number;
+use chrono::{Datelike, NaiveDate};
 
 use self::JapaneseEra::*;
+use self::ParseEraDateError::*;
+use self::RegisterEraError::*;
+
+static PROCLAIMED_ERAS: RwLock<Vec<ProclaimedEra>> = RwLock::new(Vec::new());
 
 /// Japanese era.
 ///
 /// It derives the Japanese era names.
 ///
 /// Please note that it is not possible to derive Japanese eras before the Meiji era.
-/// Prior to the Meiji era, the derivation is based on the lunar calendar, which is not currently supported.
+/// For the eras prior to the Meiji era, use [`HistoricalEra`](crate::HistoricalEra) instead.
+///
+/// The era after Reiwa can be registered at runtime with [`JapaneseEra::register`].
+/// New variants may be added in the future when a new era is proclaimed.
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
 pub enum JapaneseEra {
+    /// Era registered at runtime
+    Proclaimed(ProclaimedEra, u8),
     /// 令和
     Reiwa(u8),
     /// 平成
@@ -24,8 +39,74 @@ pub enum JapaneseEra {
 }
 
 impl JapaneseEra {
+    /// Returns the abbreviation of Japanese era.
+    /// The era year will be ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::JapaneseEra;
+    ///
+    /// assert_eq!("R", JapaneseEra::Reiwa(1).abbreviation());
+    /// ```
+    pub const fn abbreviation(&self) -> &'static str {
+        match self {
+            Proclaimed(e, _) => e.abbreviation,
+            Reiwa(_) => "R",
+            Heisei(_) => "H",
+            Showa(_) => "S",
+            Taisho(_) => "T",
+            Meiji(_) => "M",
+        }
+    }
+
+    /// Returns the last day of the era.
+    /// The era year will be ignored, and `None` is returned if the era is not yet over.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseEra;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2019, 4, 30).unwrap();
+    /// assert_eq!(Some(date), JapaneseEra::Heisei(1).end_date());
+    /// ```
+    pub fn end_date(&self) -> Option<NaiveDate> {
+        self.until()
+            .and_then(|d| NaiveDate::from_ymd_opt(d.year, d.month, d.day))
+    }
+
+    /// Returns the first day of the era year.
+    /// `None` is returned if the era year is not within the era.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseEra;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(1989, 1, 8).unwrap();
+    /// assert_eq!(Some(date), JapaneseEra::Heisei(1).first_day());
+    ///
+    /// let date = NaiveDate::from_ymd_opt(1990, 1, 1).unwrap();
+    /// assert_eq!(Some(date), JapaneseEra::Heisei(2).first_day());
+    ///
+    /// assert!(JapaneseEra::Heisei(32).first_day().is_none());
+    /// ```
+    pub fn first_day(&self) -> Option<NaiveDate> {
+        let first = NaiveDate::from_ymd_opt(self.to_western_year(), 1, 1)?.max(self.start_date());
+
+        match (self.year(), self.end_date()) {
+            (0, _) => None,
+            (_, Some(end)) if end < first => None,
+            _ => Some(first),
+        }
+    }
+
     /// Generate from Datelike of chrono.
     /// The supported period extends up to the Meiji era.
+    /// Eras registered with [`JapaneseEra::register`] are also derived.
     ///
     /// # Example
     ///
@@ -43,11 +124,33 @@ impl JapaneseEra {
             day: date.day(),
         };
 
-        JapaneseEra::reiwa(&current)
-            .or(JapaneseEra::heisei(&current))
-            .or(JapaneseEra::showa(&current))
-            .or(JapaneseEra::taisho(&current))
-            .or(JapaneseEra::meiji(&current))
+        with_proclaimed_eras(|proclaimed| {
+            JapaneseEra::eras(proclaimed).find_map(|e| e.within(&current, proclaimed))
+        })
+    }
+
+    /// Returns the last day of the era year.
+    /// `None` is returned if the era year is not within the era.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseEra;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(1989, 1, 7).unwrap();
+    /// assert_eq!(Some(date), JapaneseEra::Showa(64).last_day());
+    ///
+    /// let date = NaiveDate::from_ymd_opt(1988, 12, 31).unwrap();
+    /// assert_eq!(Some(date), JapaneseEra::Showa(63).last_day());
+    ///
+    /// assert!(JapaneseEra::Showa(65).last_day().is_none());
+    /// ```
+    pub fn last_day(&self) -> Option<NaiveDate> {
+        let last = NaiveDate::from_ymd_opt(self.to_western_year(), 12, 31)?;
+
+        self.first_day()
+            .map(|_| self.end_date().map_or(last, |end| last.min(end)))
     }
 
     /// Returns the name of Japanese era.
@@ -62,6 +165,7 @@ impl JapaneseEra {
     /// ```
     pub const fn name(&self) -> &'static str {
         match self {
+            Proclaimed(e, _) => e.name,
             Reiwa(_) => "令和",
             Heisei(_) => "平成",
             Showa(_) => "昭和",
@@ -70,107 +174,423 @@ impl JapaneseEra {
         }
     }
 
-    fn heisei(date: &InternalDate) -> Option<Self> {
-        let begin = InternalDate {
-            year: 1989,
-            month: 1,
-            day: 8,
-        };
-        let until = InternalDate {
-            year: 2019,
-            month: 4,
-            day: 30,
+    /// Parse a date written in Japanese era into `NaiveDate`.
+    ///
+    /// The era can be written by its name (`令和`) or its abbreviation (`R`),
+    /// and the first year of era can be written as `元年`.
+    /// Full-width digits and kanji numerals are also accepted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{JapaneseEra, ParseEraDateError};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+    /// assert_eq!(Ok(date), JapaneseEra::parse_date("令和6年5月1日"));
+    /// assert_eq!(Ok(date), JapaneseEra::parse_date("R6.5.1"));
+    ///
+    /// let date = NaiveDate::from_ymd_opt(1989, 1, 7).unwrap();
+    /// assert_eq!(Ok(date), JapaneseEra::parse_date("昭和６４年１月７日"));
+    ///
+    /// // Heisei starts from 1989-01-08.
+    /// assert_eq!(Err(ParseEraDateError::OutOfEra), JapaneseEra::parse_date("平成元年1月7日"));
+    /// ```
+    pub fn parse_date(s: &str) -> Result<NaiveDate, ParseEraDateError> {
+        let normalized: String = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(normalize)
+            .collect();
+        let (era, rest) = JapaneseEra::split_era(&normalized)?;
+        let (year, month, day) = split_ymd(rest).ok_or(InvalidFormat)?;
+
+        let year = match year {
+            "元" => 1,
+            y => parse_number(y).ok_or(InvalidFormat)?,
         };
+        let month = parse_number(month)
+            .and_then(|m| u32::try_from(m).ok())
+            .ok_or(InvalidFormat)?;
+        let day = parse_number(day)
+            .and_then(|d| u32::try_from(d).ok())
+            .ok_or(InvalidFormat)?;
 
-        match &begin <= date && date <= &until {
-            false => None,
-            true => {
-                let base = date.year + 12;
-                let year = if base < 2000 {
-                    base - 1900
-                } else {
-                    base - 2000
-                };
-                Some(Heisei(year as u8))
-            }
+        let era = u8::try_from(year)
+            .ok()
+            .map(|y| era.with_year(y))
+            .filter(|e| e.first_day().is_some())
+            .ok_or(YearOutOfRange)?;
+
+        let date = NaiveDate::from_ymd_opt(era.to_western_year(), month, day).ok_or(InvalidDate)?;
+        match JapaneseEra::from_datelike(&date) {
+            Some(e) if e == era => Ok(date),
+            _ => Err(OutOfEra),
         }
     }
 
-    fn meiji(date: &InternalDate) -> Option<Self> {
+    /// Returns the reading of Japanese era in hiragana.
+    /// The era year will be ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::JapaneseEra;
+    ///
+    /// assert_eq!("れいわ", JapaneseEra::Reiwa(1).reading());
+    /// ```
+    pub const fn reading(&self) -> &'static str {
+        match self {
+            Proclaimed(e, _) => e.reading,
+            Reiwa(_) => "れいわ",
+            Heisei(_) => "へいせい",
+            Showa(_) => "しょうわ",
+            Taisho(_) => "たいしょう",
+            Meiji(_) => "めいじ",
+        }
+    }
+
+    /// Register a new Japanese era which starts after the latest era.
+    ///
+    /// Once registered, the era is honoured by [`JapaneseEra::from_datelike`],
+    /// [`JapaneseEra::parse_date`], [`JapaneseDate`](crate::JapaneseDate) and [`Koyomi`](crate::Koyomi).
+    /// This is intended to apply a newly proclaimed era from configuration without waiting for a release.
+    ///
+    /// The abbreviation must be a single ASCII letter which is not used by another era,
+    /// and it is stored in upper case.
+    ///
+    /// The registry is global to the process: a registered era is shared by every thread
+    /// and lasts until the process ends, as there is no way to unregister it.
+    /// The name, reading and abbreviation of a registered era are leaked with [`Box::leak`]
+    /// to be returned as `&'static str`, so this should be called only once for each era,
+    /// typically at startup.
+    ///
+    /// A registered era must start after the start of every registered era;
+    /// otherwise it fails with [`RegisterEraError::OverlappingEra`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{JapaneseEra, RegisterEraError};
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2019, 4, 1).unwrap();
+    /// let result = JapaneseEra::register("新元号", "しんげんごう", "N", &start);
+    ///
+    /// // It must start after Reiwa.
+    /// assert_eq!(Err(RegisterEraError::InvalidStartDate), result);
+    /// ```
+    pub fn register<T: Datelike>(
+        name: &str,
+        reading: &str,
+        abbreviation: &str,
+        start: &T,
+    ) -> Result<Self, RegisterEraError> {
+        if name.is_empty() || reading.is_empty() || abbreviation.is_empty() {
+            return Err(InvalidName);
+        }
+        if !matches!(abbreviation.as_bytes(), [c] if c.is_ascii_alphabetic()) {
+            return Err(InvalidAbbreviation);
+        }
+
         let begin = InternalDate {
-            year: 1868,
-            month: 10,
-            day: 23,
-        };
-        let until = InternalDate {
-            year: 1912,
-            month: 7,
-            day: 29,
+            year: start.year(),
+            month: start.month(),
+            day: start.day(),
         };
+        let mut eras = PROCLAIMED_ERAS.write().unwrap_or_else(|e| e.into_inner());
+        let latest = eras.last().map_or(Reiwa(1).begin(), |e| e.begin);
+        let duplicated = ERAS
+            .iter()
+            .map(|e| (e.name(), e.abbreviation()))
+            .chain(eras.iter().map(|e| (e.name, e.abbreviation)))
+            .any(|(n, a)| n == name || a.eq_ignore_ascii_case(abbreviation));
 
-        match &begin <= date && date <= &until {
-            false => None,
-            true => {
-                let year = date.year + 33 - 1900;
-                Some(Meiji(year as u8))
-            }
+        if duplicated {
+            Err(DuplicateEra)
+        } else if begin <= Reiwa(1).begin() {
+            Err(InvalidStartDate)
+        } else if begin <= latest {
+            Err(OverlappingEra)
+        } else {
+            let era = ProclaimedEra {
+                begin,
+                name: Box::leak(name.into()),
+                reading: Box::leak(reading.into()),
+                abbreviation: Box::leak(abbreviation.to_ascii_uppercase().into()),
+            };
+            eras.push(era);
+            Ok(Proclaimed(era, 1))
         }
     }
 
-    fn reiwa(date: &InternalDate) -> Option<Self> {
-        let begin = InternalDate {
-            year: 2019,
-            month: 5,
-            day: 1,
-        };
+    /// Returns the first day of the era.
+    /// The era year will be ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseEra;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2019, 5, 1).unwrap();
+    /// assert_eq!(date, JapaneseEra::Reiwa(6).start_date());
+    /// ```
+    pub fn start_date(&self) -> NaiveDate {
+        let begin = self.begin();
+        NaiveDate::from_ymd_opt(begin.year, begin.month, begin.day).unwrap()
+    }
 
-        match &begin <= date {
-            false => None,
-            true => {
-                let year = date.year - 18 - 2000;
-                Some(Reiwa(year as u8))
+    /// Returns the western year of the era year.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::JapaneseEra;
+    ///
+    /// assert_eq!(2024, JapaneseEra::Reiwa(6).to_western_year());
+    /// assert_eq!(1989, JapaneseEra::Showa(64).to_western_year());
+    /// ```
+    pub const fn to_western_year(&self) -> i32 {
+        self.begin().year + self.year() as i32 - 1
+    }
+
+    /// Returns the era year.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::JapaneseEra;
+    ///
+    /// assert_eq!(6, JapaneseEra::Reiwa(6).year());
+    /// ```
+    pub const fn year(&self) -> u8 {
+        match self {
+            Proclaimed(_, y) | Reiwa(y) | Heisei(y) | Showa(y) | Taisho(y) | Meiji(y) => *y,
+        }
+    }
+
+    const fn begin(&self) -> InternalDate {
+        match self {
+            Proclaimed(e, _) => e.begin,
+            Reiwa(_) => InternalDate {
+                year: 2019,
+                month: 5,
+                day: 1,
+            },
+            Heisei(_) => InternalDate {
+                year: 1989,
+                month: 1,
+                day: 8,
+            },
+            Showa(_) => InternalDate {
+                year: 1926,
+                month: 12,
+                day: 25,
+            },
+            Taisho(_) => InternalDate {
+                year: 1912,
+                month: 7,
+                day: 30,
+            },
+            Meiji(_) => InternalDate {
+                year: 1868,
+                month: 10,
+                day: 23,
+            },
+        }
+    }
+
+    fn eras(proclaimed: &[ProclaimedEra]) -> impl Iterator<Item = Self> + '_ {
+        proclaimed
+            .iter()
+            .rev()
+            .map(|e| Proclaimed(*e, 1))
+            .chain(ERAS)
+    }
+
+    fn split_era(s: &str) -> Result<(Self, &str), ParseEraDateError> {
+        with_proclaimed_eras(|proclaimed| {
+            JapaneseEra::eras(proclaimed).find_map(|e| {
+                s.strip_prefix(e.name())
+                    .or(s.strip_prefix(e.abbreviation()))
+                    .map(|rest| (e, rest))
+            })
+        })
+        .ok_or(match s.chars().next() {
+            Some(c) if c.is_ascii_digit() => InvalidFormat,
+            Some(_) => UnknownEra,
+            None => InvalidFormat,
+        })
+    }
+
+    fn until(&self) -> Option<InternalDate> {
+        match self {
+            Proclaimed(..) | Reiwa(_) => {
+                with_proclaimed_eras(|proclaimed| self.until_in(proclaimed))
             }
+            _ => self.until_in(&[]),
         }
     }
 
-    fn showa(date: &InternalDate) -> Option<Self> {
-        let begin = InternalDate {
-            year: 1926,
-            month: 12,
-            day: 25,
-        };
-        let until = InternalDate {
-            year: 1989,
-            month: 1,
-            day: 7,
-        };
+    fn until_in(&self, proclaimed: &[ProclaimedEra]) -> Option<InternalDate> {
+        match self {
+            // Ongoing eras last until the day before the next registered era.
+            Proclaimed(..) | Reiwa(_) => proclaimed
+                .iter()
+                .find(|p| self.begin() < p.begin)
+                .and_then(|p| NaiveDate::from_ymd_opt(p.begin.year, p.begin.month, p.begin.day))
+                .and_then(|d| d.pred_opt())
+                .map(|d| InternalDate {
+                    year: d.year(),
+                    month: d.month(),
+                    day: d.day(),
+                }),
+            Heisei(_) => Some(InternalDate {
+                year: 2019,
+                month: 4,
+                day: 30,
+            }),
+            Showa(_) => Some(InternalDate {
+                year: 1989,
+                month: 1,
+                day: 7,
+            }),
+            Taisho(_) => Some(InternalDate {
+                year: 1926,
+                month: 12,
+                day: 24,
+            }),
+            Meiji(_) => Some(InternalDate {
+                year: 1912,
+                month: 7,
+                day: 29,
+            }),
+        }
+    }
 
-        match &begin <= date && date <= &until {
+    const fn with_year(&self, year: u8) -> Self {
+        match self {
+            Proclaimed(e, _) => Proclaimed(*e, year),
+            Reiwa(_) => Reiwa(year),
+            Heisei(_) => Heisei(year),
+            Showa(_) => Showa(year),
+            Taisho(_) => Taisho(year),
+            Meiji(_) => Meiji(year),
+        }
+    }
+
+    fn within(&self, date: &InternalDate, proclaimed: &[ProclaimedEra]) -> Option<Self> {
+        let begin = self.begin();
+
+        match &begin <= date && self.until_in(proclaimed).is_none_or(|until| date <= &until) {
             false => None,
             true => {
-                let year = date.year - 25 - 1900;
-                Some(Showa(year as u8))
+                let year = date.year - begin.year + 1;
+                Some(self.with_year(year as u8))
             }
         }
     }
+}
 
-    fn taisho(date: &InternalDate) -> Option<Self> {
-        let begin = InternalDate {
-            year: 1912,
-            month: 7,
-            day: 30,
+const ERAS: [JapaneseEra; 5] = [Reiwa(1), Heisei(1), Showa(1), Taisho(1), Meiji(1)];
+
+/// Japanese era registered at runtime.
+///
+/// It is generated by [`JapaneseEra::register`].
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ProclaimedEra {
+    begin: InternalDate,
+    name: &'static str,
+    reading: &'static str,
+    abbreviation: &'static str,
+}
+
+/// Errors that can occur when registering a Japanese era.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RegisterEraError {
+    /// The name or abbreviation is already used by another era.
+    DuplicateEra,
+    /// The abbreviation is not a single ASCII letter.
+    InvalidAbbreviation,
+    /// The name, reading or abbreviation is empty.
+    InvalidName,
+    /// The start date is not after the start of Reiwa.
+    InvalidStartDate,
+    /// The start date is not after the start of an era registered before.
+    OverlappingEra,
+}
+
+impl fmt::Display for RegisterEraError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            DuplicateEra => "the era name or abbreviation is already used",
+            InvalidAbbreviation => "the era abbreviation must be a single ASCII letter",
+            InvalidName => "the era name, reading and abbreviation must not be empty",
+            InvalidStartDate => "the era must start after Reiwa",
+            OverlappingEra => "the era overlaps an era registered before",
         };
-        let until = InternalDate {
-            year: 1926,
-            month: 12,
-            day: 24,
+        f.write_str(message)
+    }
+}
+
+impl Error for RegisterEraError {}
+
+/// Errors that can occur when parsing a date written in Japanese era.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseEraDateError {
+    /// The day of month does not exist.
+    InvalidDate,
+    /// The string is not a date written in Japanese era.
+    InvalidFormat,
+    /// The date is outside the period of the era.
+    OutOfEra,
+    /// The era name or abbreviation is unknown.
+    UnknownEra,
+    /// The era year is zero or past the end of the era.
+    YearOutOfRange,
+}
+
+impl fmt::Display for ParseEraDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            InvalidDate => "the day of month does not exist",
+            InvalidFormat => "not a date written in Japanese era",
+            OutOfEra => "the date is outside the period of the era",
+            UnknownEra => "unknown Japanese era",
+            YearOutOfRange => "the era year is out of range",
         };
+        f.write_str(message)
+    }
+}
 
-        match &begin <= date && date <= &until {
-            false => None,
-            true => {
-                let year = date.year - 11 - 1900;
-                Some(Taisho(year as u8))
+impl Error for ParseEraDateError {}
+
+fn with_proclaimed_eras<R>(f: impl FnOnce(&[ProclaimedEra]) -> R) -> R {
+    f(&PROCLAIMED_ERAS.read().unwrap_or_else(|e| e.into_inner()))
+}
+
+fn normalize(c: char) -> char {
+    // Full-width ASCII characters are shifted into ASCII.
+    let c = match c {
+        '！'..='～' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        _ => c,
+    };
+    c.to_ascii_uppercase()
+}
+
+fn split_ymd(s: &str) -> Option<(&str, &str, &str)> {
+    match s.split_once('年') {
+        Some((year, rest)) => {
+            let (month, rest) = rest.split_once('月')?;
+            let day = rest.strip_suffix('日')?;
+            Some((year, month, day))
+        }
+        None => {
+            let mut parts = s.split(['.', '/', '-']);
+            match (parts.next(), parts.next(), parts.next(), parts.next()) {
+                (Some(year), Some(month), Some(day), None) => Some((year, month, day)),
+                _ => None,
             }
         }
     }
@@ -178,8 +598,8 @@ impl JapaneseEra {
 
 #[cfg(test)]
 mod tests {
-    use super::JapaneseEra;
     use super::JapaneseEra::*;
+    use super::{JapaneseEra, ParseEraDateError};
 
     use chrono::NaiveDate;
     use rstest::rstest;
@@ -253,4 +673,114 @@ mod tests {
         let date = NaiveDate::from_ymd_opt(1868, 10, 22).unwrap();
         assert!(JapaneseEra::from_datelike(&date).is_none());
     }
+
+    #[rstest]
+    #[case("令和6年5月1日", 2024, 5, 1)]
+    #[case("平成元年1月8日", 1989, 1, 8)]
+    #[case("昭和６４年１月７日", 1989, 1, 7)]
+    #[case("大正元年7月30日", 1912, 7, 30)]
+    #[case("明治45年7月29日", 1912, 7, 29)]
+    #[case("令和六年十月十七日", 2024, 10, 17)]
+    #[case("令和陸年拾月拾漆日", 2024, 10, 17)]
+    #[case("令和 6年 5月 1日", 2024, 5, 1)]
+    #[case("R6.5.1", 2024, 5, 1)]
+    #[case("h31/4/30", 2019, 4, 30)]
+    #[case("Ｓ６４－１－７", 1989, 1, 7)]
+    #[case("T15.12.24", 1926, 12, 24)]
+    #[case("M1.10.23", 1868, 10, 23)]
+    fn 和暦の日付を解析できる(
+        #[case] s: &str,
+        #[case] year: i32,
+        #[case] month: u32,
+        #[case] day: u32,
+    ) {
+        let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        assert_eq!(Ok(date), JapaneseEra::parse_date(s));
+    }
+
+    #[rstest]
+    #[case("", ParseEraDateError::InvalidFormat)]
+    #[case("2024年5月1日", ParseEraDateError::InvalidFormat)]
+    #[case("令和6年5月", ParseEraDateError::InvalidFormat)]
+    #[case("R6.5", ParseEraDateError::InvalidFormat)]
+    #[case("令和x年5月1日", ParseEraDateError::InvalidFormat)]
+    #[case("令和一二十年5月1日", ParseEraDateError::InvalidFormat)]
+    #[case("令和十十年5月1日", ParseEraDateError::InvalidFormat)]
+    #[case("慶応4年1月1日", ParseEraDateError::UnknownEra)]
+    #[case("X6.5.1", ParseEraDateError::UnknownEra)]
+    #[case("令和0年5月1日", ParseEraDateError::YearOutOfRange)]
+    #[case("平成32年1月1日", ParseEraDateError::YearOutOfRange)]
+    #[case("昭和65年1月1日", ParseEraDateError::YearOutOfRange)]
+    #[case("令和6年2月30日", ParseEraDateError::InvalidDate)]
+    #[case("平成元年1月7日", ParseEraDateError::OutOfEra)]
+    #[case("平成31年5月1日", ParseEraDateError::OutOfEra)]
+    #[case("令和元年4月30日", ParseEraDateError::OutOfEra)]
+    fn 和暦の日付として不正な文字列は解析できない(
+        #[case] s: &str,
+        #[case] expect: ParseEraDateError,
+    ) {
+        assert_eq!(Err(expect), JapaneseEra::parse_date(s));
+    }
+
+    #[rstest]
+    #[case(Reiwa(6), 2024)]
+    #[case(Heisei(1), 1989)]
+    #[case(Heisei(31), 2019)]
+    #[case(Showa(64), 1989)]
+    #[case(Taisho(15), 1926)]
+    #[case(Meiji(45), 1912)]
+    fn 和暦の年から西暦の年に変換できる(
+        #[case] era: JapaneseEra,
+        #[case] expect: i32,
+    ) {
+        assert_eq!(expect, era.to_western_year());
+    }
+
+    #[rstest]
+    #[case(Reiwa(1), (2019, 5, 1), (2019, 12, 31))]
+    #[case(Reiwa(6), (2024, 1, 1), (2024, 12, 31))]
+    #[case(Heisei(1), (1989, 1, 8), (1989, 12, 31))]
+    #[case(Heisei(31), (2019, 1, 1), (2019, 4, 30))]
+    #[case(Showa(1), (1926, 12, 25), (1926, 12, 31))]
+    #[case(Showa(64), (1989, 1, 1), (1989, 1, 7))]
+    #[case(Taisho(15), (1926, 1, 1), (1926, 12, 24))]
+    #[case(Meiji(1), (1868, 10, 23), (1868, 12, 31))]
+    fn 和暦の年の期間を導出できる(
+        #[case] era: JapaneseEra,
+        #[case] first: (i32, u32, u32),
+        #[case] last: (i32, u32, u32),
+    ) {
+        let first = NaiveDate::from_ymd_opt(first.0, first.1, first.2).unwrap();
+        let last = NaiveDate::from_ymd_opt(last.0, last.1, last.2).unwrap();
+        assert_eq!(Some(first), era.first_day());
+        assert_eq!(Some(last), era.last_day());
+    }
+
+    #[rstest]
+    #[case(Reiwa(0))]
+    #[case(Heisei(32))]
+    #[case(Showa(65))]
+    #[case(Taisho(16))]
+    #[case(Meiji(46))]
+    fn 元号の期間外の年は期間を導出できない(#[case] era: JapaneseEra) {
+        assert!(era.first_day().is_none());
+        assert!(era.last_day().is_none());
+    }
+
+    #[rstest]
+    #[case(Reiwa(1), (2019, 5, 1), None)]
+    #[case(Heisei(1), (1989, 1, 8), Some((2019, 4, 30)))]
+    #[case(Showa(1), (1926, 12, 25), Some((1989, 1, 7)))]
+    #[case(Taisho(1), (1912, 7, 30), Some((1926, 12, 24)))]
+    #[case(Meiji(1), (1868, 10, 23), Some((1912, 7, 29)))]
+    fn 元号の期間を導出できる(
+        #[case] era: JapaneseEra,
+        #[case] start: (i32, u32, u32),
+        #[case] end: Option<(i32, u32, u32)>,
+    ) {
+        let start = NaiveDate::from_ymd_opt(start.0, start.1, start.2).unwrap();
+        let end = end.and_then(|(y, m, d)| NaiveDate::from_ymd_opt(y, m, d));
+        assert_eq!(start, era.start_date());
+        assert_eq!(end, era.end_date());
+    }
 }
@@ -0,0 +1,167 @@
+use std::fmt;
+
+use crate::day::JapaneseHoliday;
+use crate::koyomi::JapaneseDate;
+use crate::numerals::{to_daiji, to_full_width, to_kanji, to_positional_kanji};
+use crate::provider::Holiday;
+
+/// A formatted `JapaneseDate`.
+///
+/// It's created by [`JapaneseDate::format`] and formats the date when displayed.
+///
+/// The following specifiers are supported.
+///
+/// | Specifier | Example | Description |
+/// | --------- | ------- | ----------- |
+/// | `%Y` | `2024` | Western year. |
+/// | `%m` | `10` | Month number. |
+/// | `%d` | `17` | Day. |
+/// | `%E` | `令和` | Name of Japanese era. |
+/// | `%e` | `R` | Abbreviation of Japanese era. |
+/// | `%y` | `1` | Year of Japanese era. |
+/// | `%J` | `元` | Year of Japanese era, the first year is written as `元`. |
+/// | `%a` | `木` | Short name of weekday. |
+/// | `%A` | `木曜日` | Long name of weekday. |
+/// | `%B` | `神無月` | Japanese name of month. |
+/// | `%G` | `甲辰` | Sexagenary cycle of year. |
+/// | `%%` | `%` | Literal `%`. |
+///
+/// Numeric specifiers (`%Y`, `%m`, `%d`, `%y` and `%J`) can be prefixed with a modifier.
+///
+/// | Modifier | Example | Description |
+/// | -------- | ------- | ----------- |
+/// | `K` | `十七` | Kanji numerals. `%KY` is written in positional style such as `二〇二四`. |
+/// | `D` | `壱拾漆` | 大字 (formal kanji numerals). |
+/// | `F` | `１７` | Full-width digits. |
+///
+/// See [`numerals`](crate::numerals) for these notations.
+///
+/// The era specifiers write nothing for dates before the Meiji era.
+/// An unknown specifier results in `fmt::Error`.
+#[derive(Debug)]
+pub struct JapaneseDateFormat<'a, H = JapaneseHoliday> {
+    date: &'a JapaneseDate<H>,
+    fmt: &'a str,
+}
+
+impl<'a, H> JapaneseDateFormat<'a, H> {
+    pub(crate) const fn new(date: &'a JapaneseDate<H>, fmt: &'a str) -> Self {
+        Self { date, fmt }
+    }
+}
+
+impl<H: Holiday> fmt::Display for JapaneseDateFormat<'_, H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let date = self.date;
+        let era = date.era();
+        let mut chars = self.fmt.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                write!(f, "{}", c)?;
+                continue;
+            }
+
+            let (modifier, spec) = match chars.next() {
+                Some(m @ ('K' | 'D' | 'F')) => (Some(m), chars.next()),
+                spec => (None, spec),
+            };
+            let number = |n: u64| match modifier {
+                Some('K') => to_kanji(n),
+                Some('D') => to_daiji(n),
+                Some('F') => to_full_width(&n.to_string()),
+                _ => n.to_string(),
+            };
+
+            match spec {
+                Some('Y') => {
+                    let year = u64::from(date.western_year().unsigned_abs());
+                    if date.western_year() < 0 {
+                        write!(f, "-")?;
+                    }
+                    match modifier {
+                        Some('K') => write!(f, "{}", to_positional_kanji(year))?,
+                        _ => write!(f, "{}", number(year))?,
+                    }
+                }
+                Some('m') => write!(f, "{}", number(u64::from(date.month_number())))?,
+                Some('d') => write!(f, "{}", number(u64::from(date.day())))?,
+                Some('y') => {
+                    if let Some(e) = era {
+                        write!(f, "{}", number(u64::from(e.year())))?
+                    }
+                }
+                Some('J') => match era {
+                    Some(e) if e.year() == 1 => write!(f, "元")?,
+                    Some(e) => write!(f, "{}", number(u64::from(e.year())))?,
+                    None => {}
+                },
+                Some('E') if modifier.is_none() => {
+                    if let Some(e) = era {
+                        write!(f, "{}", e.name())?
+                    }
+                }
+                Some('e') if modifier.is_none() => {
+                    if let Some(e) = era {
+                        write!(f, "{}", e.abbreviation())?
+                    }
+                }
+                Some('a') if modifier.is_none() => write!(f, "{}", date.weekday_name())?,
+                Some('A') if modifier.is_none() => write!(f, "{}曜日", date.weekday_name())?,
+                Some('B') if modifier.is_none() => write!(f, "{}", date.month_name())?,
+                Some('G') if modifier.is_none() => write!(f, "{}", date.sexagenary_cycle_name())?,
+                Some('%') if modifier.is_none() => write!(f, "%")?,
+                _ => return Err(fmt::Error),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::koyomi::JapaneseDate;
+    use chrono::NaiveDate;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case((2024, 10, 17), "%E%y年%m月%d日(%a)", "令和6年10月17日(木)")]
+    #[case((2024, 10, 17), "%E%KJ年%Km月%Kd日 %A", "令和六年十月十七日 木曜日")]
+    #[case((2024, 10, 17), "%e%y.%m.%d", "R6.10.17")]
+    #[case((2024, 10, 17), "%KY年 %B %G", "二〇二四年 神無月 甲辰")]
+    #[case((2019, 5, 1), "%E%J年%m月%d日", "令和元年5月1日")]
+    #[case((2019, 5, 1), "%E%y年", "令和1年")]
+    #[case((1989, 1, 7), "%E%KJ年", "昭和六十四年")]
+    #[case((1868, 10, 22), "%Y年%m月%d日", "1868年10月22日")]
+    #[case((1868, 10, 22), "%E%y年(%Y年)", "年(1868年)")]
+    #[case((1800, 1, 1), "%e%J.%m.%d", ".1.1")]
+    #[case((2024, 10, 17), "%E%DJ年%Dm月%Dd日", "令和陸年壱拾月壱拾漆日")]
+    #[case((2024, 10, 17), "%FY年%Fm月%Fd日", "２０２４年１０月１７日")]
+    #[case((2024, 1, 1), "100%%", "100%")]
+    fn 書式を指定して日付を文字列にできる(
+        #[case] ymd: (i32, u32, u32),
+        #[case] fmt: &str,
+        #[case] expect: &str,
+    ) {
+        let date = NaiveDate::from_ymd_opt(ymd.0, ymd.1, ymd.2).unwrap();
+        let japanese_date = JapaneseDate::from_datelike(&date);
+
+        assert_eq!(expect, japanese_date.format(fmt).to_string());
+    }
+
+    #[rstest]
+    #[case("%Q")]
+    #[case("%KE")]
+    #[case("%Fa")]
+    #[case("%")]
+    fn 不明な書式は書式化できない(#[case] fmt: &str) {
+        use std::fmt::Write;
+
+        let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let japanese_date = JapaneseDate::from_datelike(&date);
+        let mut s = String::new();
+
+        assert!(write!(s, "{}", japanese_date.format(fmt)).is_err());
+    }
+}
@@ -0,0 +1,588 @@
+use crate::internal::InternalDate;
+use chrono::{Datelike, NaiveDate};
+
+use self::Court::*;
+
+/// Imperial court during the Northern and Southern Courts period.
+///
+/// From 1336 to 1392, the Northern court and the Southern court used different era names.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Court {
+    /// 北朝
+    Northern,
+    /// 南朝
+    Southern,
+}
+
+impl Court {
+    /// Returns the name of imperial court.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Court;
+    ///
+    /// assert_eq!("南朝", Court::Southern.name());
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Northern => "北朝",
+            Southern => "南朝",
+        }
+    }
+}
+
+/// Historical Japanese era.
+///
+/// It derives the Japanese era names from 大化 to 慶応, which are prior to the Meiji era.
+///
+/// The start and end dates are in the proleptic Gregorian calendar, converted from the lunisolar
+/// dates on which each era was proclaimed. Since they are calculated astronomically,
+/// they may differ by a day from historical records.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HistoricalEra {
+    name: &'static str,
+    reading: &'static str,
+    court: Option<Court>,
+    begin: InternalDate,
+    until: InternalDate,
+}
+
+impl HistoricalEra {
+    /// Returns the imperial court which used the era.
+    /// `None` is returned if the era was not used during the Northern and Southern Courts period.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::{Court, HistoricalEra};
+    ///
+    /// let era = HistoricalEra::from_name("正平").unwrap();
+    /// assert_eq!(Some(Court::Southern), era.court());
+    /// ```
+    pub const fn court(&self) -> Option<Court> {
+        self.court
+    }
+
+    /// Returns the last day of the era.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::HistoricalEra;
+    ///
+    /// let era = HistoricalEra::from_name("慶応").unwrap();
+    /// assert_eq!(NaiveDate::from_ymd_opt(1868, 10, 22).unwrap(), era.end_date());
+    /// ```
+    pub fn end_date(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.until.year, self.until.month, self.until.day).unwrap()
+    }
+
+    /// Generate from Datelike of chrono.
+    /// The supported period is from 大化 to 慶応.
+    ///
+    /// During the Northern and Southern Courts period, the era of the Southern court is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::HistoricalEra;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(1600, 10, 21).unwrap();
+    /// assert_eq!("慶長", HistoricalEra::from_datelike(&date).unwrap().name());
+    /// ```
+    pub fn from_datelike<T: Datelike>(date: &T) -> Option<Self> {
+        HistoricalEra::from_datelike_with_court(date, Southern)
+    }
+
+    /// Generate from Datelike of chrono with the imperial court.
+    /// The court is considered only during the Northern and Southern Courts period.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{Court, HistoricalEra};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(1350, 1, 1).unwrap();
+    /// let northern = HistoricalEra::from_datelike_with_court(&date, Court::Northern).unwrap();
+    /// let southern = HistoricalEra::from_datelike_with_court(&date, Court::Southern).unwrap();
+    ///
+    /// assert_eq!("貞和", northern.name());
+    /// assert_eq!("正平", southern.name());
+    /// ```
+    pub fn from_datelike_with_court<T: Datelike>(date: &T, court: Court) -> Option<Self> {
+        let current = InternalDate {
+            year: date.year(),
+            month: date.month(),
+            day: date.day(),
+        };
+        let mut candidates = HISTORICAL_ERAS
+            .iter()
+            .filter(|e| e.begin <= current && current <= e.until);
+
+        // After the unification, the era of the Northern court is used.
+        candidates
+            .clone()
+            .find(|e| e.court == Some(court))
+            .or(candidates.clone().find(|e| e.court.is_none()))
+            .or(candidates.next())
+            .copied()
+    }
+
+    /// Generate from name of historical era.
+    ///
+    /// 元徳 is continued by the Northern court after 元弘 is proclaimed,
+    /// but the era before the split is returned for the name.
+    /// Use [`HistoricalEra::from_datelike_with_court`] for the Northern court's 元徳.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::HistoricalEra;
+    ///
+    /// assert!(HistoricalEra::from_name("大化").is_some());
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        HISTORICAL_ERAS.iter().find(|e| e.name == name).copied()
+    }
+
+    /// Returns the name of historical era.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::HistoricalEra;
+    ///
+    /// let era = HistoricalEra::from_name("元禄").unwrap();
+    /// assert_eq!("元禄", era.name());
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the reading of historical era in hiragana.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::HistoricalEra;
+    ///
+    /// let era = HistoricalEra::from_name("元禄").unwrap();
+    /// assert_eq!("げんろく", era.reading());
+    /// ```
+    pub const fn reading(&self) -> &'static str {
+        self.reading
+    }
+
+    /// Returns the first day of the era.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::HistoricalEra;
+    ///
+    /// let era = HistoricalEra::from_name("大化").unwrap();
+    /// assert_eq!(NaiveDate::from_ymd_opt(645, 7, 20).unwrap(), era.start_date());
+    /// ```
+    pub fn start_date(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.begin.year, self.begin.month, self.begin.day).unwrap()
+    }
+}
+
+const fn era(
+    name: &'static str,
+    reading: &'static str,
+    court: Option<Court>,
+    begin: (i32, u32, u32),
+    until: (i32, u32, u32),
+) -> HistoricalEra {
+    HistoricalEra {
+        name,
+        reading,
+        court,
+        begin: InternalDate {
+            year: begin.0,
+            month: begin.1,
+            day: begin.2,
+        },
+        until: InternalDate {
+            year: until.0,
+            month: until.1,
+            day: until.2,
+        },
+    }
+}
+
+#[rustfmt::skip]
+const HISTORICAL_ERAS: [HistoricalEra; 244] = [
+    era("大化", "たいか", None, (645, 7, 20), (650, 3, 24)),
+    era("白雉", "はくち", None, (650, 3, 25), (654, 11, 27)),
+    era("朱鳥", "しゅちょう", None, (686, 8, 17), (686, 10, 4)),
+    era("大宝", "たいほう", None, (701, 5, 7), (704, 6, 19)),
+    era("慶雲", "けいうん", None, (704, 6, 20), (708, 2, 10)),
+    era("和銅", "わどう", None, (708, 2, 11), (715, 10, 6)),
+    era("霊亀", "れいき", None, (715, 10, 7), (717, 12, 27)),
+    era("養老", "ようろう", None, (717, 12, 28), (724, 3, 6)),
+    era("神亀", "じんき", None, (724, 3, 7), (729, 9, 5)),
+    era("天平", "てんぴょう", None, (729, 9, 6), (749, 5, 8)),
+    era("天平感宝", "てんぴょうかんぽう", None, (749, 5, 9), (749, 8, 22)),
+    era("天平勝宝", "てんぴょうしょうほう", None, (749, 8, 23), (757, 9, 9)),
+    era("天平宝字", "てんぴょうほうじ", None, (757, 9, 10), (765, 2, 4)),
+    era("天平神護", "てんぴょうじんご", None, (765, 2, 5), (767, 9, 16)),
+    era("神護景雲", "じんごけいうん", None, (767, 9, 17), (770, 10, 26)),
+    era("宝亀", "ほうき", None, (770, 10, 27), (781, 2, 1)),
+    era("天応", "てんおう", None, (781, 2, 2), (782, 10, 3)),
+    era("延暦", "えんりゃく", None, (782, 10, 4), (806, 6, 11)),
+    era("大同", "だいどう", None, (806, 6, 12), (810, 10, 23)),
+    era("弘仁", "こうにん", None, (810, 10, 24), (824, 2, 11)),
+    era("天長", "てんちょう", None, (824, 2, 12), (834, 2, 17)),
+    era("承和", "じょうわ", None, (834, 2, 18), (848, 7, 19)),
+    era("嘉祥", "かしょう", None, (848, 7, 20), (851, 6, 4)),
+    era("仁寿", "にんじゅ", None, (851, 6, 5), (854, 12, 26)),
+    era("斉衡", "さいこう", None, (854, 12, 27), (857, 3, 23)),
+    era("天安", "てんあん", None, (857, 3, 24), (859, 5, 23)),
+    era("貞観", "じょうがん", None, (859, 5, 24), (877, 6, 4)),
+    era("元慶", "がんぎょう", None, (877, 6, 5), (885, 3, 14)),
+    era("仁和", "にんな", None, (885, 3, 15), (889, 6, 2)),
+    era("寛平", "かんぴょう", None, (889, 6, 3), (898, 5, 23)),
+    era("昌泰", "しょうたい", None, (898, 5, 24), (901, 9, 4)),
+    era("延喜", "えんぎ", None, (901, 9, 5), (923, 5, 3)),
+    era("延長", "えんちょう", None, (923, 5, 4), (931, 5, 20)),
+    era("承平", "じょうへい", None, (931, 5, 21), (938, 6, 26)),
+    era("天慶", "てんぎょう", None, (938, 6, 27), (947, 5, 19)),
+    era("天暦", "てんりゃく", None, (947, 5, 20), (957, 11, 25)),
+    era("天徳", "てんとく", None, (957, 11, 26), (961, 3, 9)),
+    era("応和", "おうわ", None, (961, 3, 10), (964, 8, 23)),
+    era("康保", "こうほう", None, (964, 8, 24), (968, 9, 12)),
+    era("安和", "あんな", None, (968, 9, 13), (970, 5, 7)),
+    era("天禄", "てんろく", None, (970, 5, 8), (974, 1, 19)),
+    era("天延", "てんえん", None, (974, 1, 20), (976, 8, 15)),
+    era("貞元", "じょうげん", None, (976, 8, 16), (979, 1, 4)),
+    era("天元", "てんげん", None, (979, 1, 5), (983, 6, 2)),
+    era("永観", "えいかん", None, (983, 6, 3), (985, 5, 23)),
+    era("寛和", "かんな", None, (985, 5, 24), (987, 5, 9)),
+    era("永延", "えいえん", None, (987, 5, 10), (989, 9, 14)),
+    era("永祚", "えいそ", None, (989, 9, 15), (990, 11, 30)),
+    era("正暦", "しょうりゃく", None, (990, 12, 1), (995, 3, 29)),
+    era("長徳", "ちょうとく", None, (995, 3, 30), (999, 2, 5)),
+    era("長保", "ちょうほう", None, (999, 2, 6), (1004, 8, 13)),
+    era("寛弘", "かんこう", None, (1004, 8, 14), (1013, 2, 12)),
+    era("長和", "ちょうわ", None, (1013, 2, 13), (1017, 5, 26)),
+    era("寛仁", "かんにん", None, (1017, 5, 27), (1021, 3, 22)),
+    era("治安", "じあん", None, (1021, 3, 23), (1024, 8, 24)),
+    era("万寿", "まんじゅ", None, (1024, 8, 25), (1028, 8, 23)),
+    era("長元", "ちょうげん", None, (1028, 8, 24), (1037, 5, 13)),
+    era("長暦", "ちょうりゃく", None, (1037, 5, 14), (1040, 12, 21)),
+    era("長久", "ちょうきゅう", None, (1040, 12, 22), (1044, 12, 20)),
+    era("寛徳", "かんとく", None, (1044, 12, 21), (1046, 5, 27)),
+    era("永承", "えいしょう", None, (1046, 5, 28), (1053, 2, 7)),
+    era("天喜", "てんぎ", None, (1053, 2, 8), (1058, 9, 24)),
+    era("康平", "こうへい", None, (1058, 9, 25), (1065, 9, 9)),
+    era("治暦", "じりゃく", None, (1065, 9, 10), (1069, 5, 11)),
+    era("延久", "えんきゅう", None, (1069, 5, 12), (1074, 9, 21)),
+    era("承保", "じょうほう", None, (1074, 9, 22), (1077, 12, 9)),
+    era("承暦", "じょうりゃく", None, (1077, 12, 10), (1081, 3, 27)),
+    era("永保", "えいほう", None, (1081, 3, 28), (1084, 3, 20)),
+    era("応徳", "おうとく", None, (1084, 3, 21), (1087, 5, 16)),
+    era("寛治", "かんじ", None, (1087, 5, 17), (1095, 1, 28)),
+    era("嘉保", "かほう", None, (1095, 1, 29), (1097, 1, 8)),
+    era("永長", "えいちょう", None, (1097, 1, 9), (1098, 1, 1)),
+    era("承徳", "じょうとく", None, (1098, 1, 2), (1099, 9, 20)),
+    era("康和", "こうわ", None, (1099, 9, 21), (1104, 3, 14)),
+    era("長治", "ちょうじ", None, (1104, 3, 15), (1106, 5, 19)),
+    era("嘉承", "かじょう", None, (1106, 5, 20), (1108, 9, 15)),
+    era("天仁", "てんにん", None, (1108, 9, 16), (1110, 8, 6)),
+    era("天永", "てんえい", None, (1110, 8, 7), (1113, 8, 31)),
+    era("永久", "えいきゅう", None, (1113, 9, 1), (1118, 5, 1)),
+    era("元永", "げんえい", None, (1118, 5, 2), (1120, 5, 14)),
+    era("保安", "ほうあん", None, (1120, 5, 15), (1124, 5, 23)),
+    era("天治", "てんじ", None, (1124, 5, 24), (1126, 2, 21)),
+    era("大治", "だいじ", None, (1126, 2, 22), (1131, 3, 6)),
+    era("天承", "てんしょう", None, (1131, 3, 7), (1132, 9, 27)),
+    era("長承", "ちょうしょう", None, (1132, 9, 28), (1135, 6, 16)),
+    era("保延", "ほうえん", None, (1135, 6, 17), (1141, 8, 19)),
+    era("永治", "えいじ", None, (1141, 8, 20), (1142, 5, 30)),
+    era("康治", "こうじ", None, (1142, 5, 31), (1144, 4, 3)),
+    era("天養", "てんよう", None, (1144, 4, 4), (1145, 8, 18)),
+    era("久安", "きゅうあん", None, (1145, 8, 19), (1151, 2, 20)),
+    era("仁平", "にんぺい", None, (1151, 2, 21), (1154, 12, 10)),
+    era("久寿", "きゅうじゅ", None, (1154, 12, 11), (1156, 5, 24)),
+    era("保元", "ほうげん", None, (1156, 5, 25), (1159, 5, 15)),
+    era("平治", "へいじ", None, (1159, 5, 16), (1160, 2, 24)),
+    era("永暦", "えいりゃく", None, (1160, 2, 25), (1161, 9, 30)),
+    era("応保", "おうほう", None, (1161, 10, 1), (1163, 5, 9)),
+    era("長寛", "ちょうかん", None, (1163, 5, 10), (1165, 7, 20)),
+    era("永万", "えいまん", None, (1165, 7, 21), (1166, 9, 28)),
+    era("仁安", "にんあん", None, (1166, 9, 29), (1169, 5, 12)),
+    era("嘉応", "かおう", None, (1169, 5, 13), (1171, 6, 2)),
+    era("承安", "じょうあん", None, (1171, 6, 3), (1175, 8, 22)),
+    era("安元", "あんげん", None, (1175, 8, 23), (1177, 9, 3)),
+    era("治承", "じしょう", None, (1177, 9, 4), (1181, 8, 31)),
+    era("養和", "ようわ", None, (1181, 9, 1), (1182, 7, 5)),
+    era("寿永", "じゅえい", None, (1182, 7, 6), (1184, 6, 2)),
+    era("元暦", "げんりゃく", None, (1184, 6, 3), (1185, 9, 15)),
+    era("文治", "ぶんじ", None, (1185, 9, 16), (1190, 5, 22)),
+    era("建久", "けんきゅう", None, (1190, 5, 23), (1199, 5, 29)),
+    era("正治", "しょうじ", None, (1199, 5, 30), (1201, 3, 24)),
+    era("建仁", "けんにん", None, (1201, 3, 25), (1204, 3, 28)),
+    era("元久", "げんきゅう", None, (1204, 3, 29), (1206, 6, 11)),
+    era("建永", "けんえい", None, (1206, 6, 12), (1207, 11, 22)),
+    era("承元", "じょうげん", None, (1207, 11, 23), (1211, 4, 29)),
+    era("建暦", "けんりゃく", None, (1211, 4, 30), (1214, 1, 24)),
+    era("建保", "けんぽう", None, (1214, 1, 25), (1219, 6, 1)),
+    era("承久", "じょうきゅう", None, (1219, 6, 2), (1222, 5, 31)),
+    era("貞応", "じょうおう", None, (1222, 6, 1), (1225, 1, 6)),
+    era("元仁", "げんにん", None, (1225, 1, 7), (1225, 6, 3)),
+    era("嘉禄", "かろく", None, (1225, 6, 4), (1228, 1, 24)),
+    era("安貞", "あんてい", None, (1228, 1, 25), (1229, 4, 6)),
+    era("寛喜", "かんぎ", None, (1229, 4, 7), (1232, 4, 29)),
+    era("貞永", "じょうえい", None, (1232, 4, 30), (1233, 5, 31)),
+    era("天福", "てんぷく", None, (1233, 6, 1), (1234, 12, 3)),
+    era("文暦", "ぶんりゃく", None, (1234, 12, 4), (1235, 11, 7)),
+    era("嘉禎", "かてい", None, (1235, 11, 8), (1239, 1, 5)),
+    era("暦仁", "りゃくにん", None, (1239, 1, 6), (1239, 3, 19)),
+    era("延応", "えんおう", None, (1239, 3, 20), (1240, 8, 11)),
+    era("仁治", "にんじ", None, (1240, 8, 12), (1243, 3, 23)),
+    era("寛元", "かんげん", None, (1243, 3, 24), (1247, 4, 11)),
+    era("宝治", "ほうじ", None, (1247, 4, 12), (1249, 5, 8)),
+    era("建長", "けんちょう", None, (1249, 5, 9), (1256, 10, 30)),
+    era("康元", "こうげん", None, (1256, 10, 31), (1257, 4, 6)),
+    era("正嘉", "しょうか", None, (1257, 4, 7), (1259, 4, 25)),
+    era("正元", "しょうげん", None, (1259, 4, 26), (1260, 5, 30)),
+    era("文応", "ぶんおう", None, (1260, 5, 31), (1261, 3, 28)),
+    era("弘長", "こうちょう", None, (1261, 3, 29), (1264, 4, 2)),
+    era("文永", "ぶんえい", None, (1264, 4, 3), (1275, 5, 27)),
+    era("建治", "けんじ", None, (1275, 5, 28), (1278, 3, 29)),
+    era("弘安", "こうあん", None, (1278, 3, 30), (1288, 6, 4)),
+    era("正応", "しょうおう", None, (1288, 6, 5), (1293, 9, 12)),
+    era("永仁", "えいにん", None, (1293, 9, 13), (1299, 5, 31)),
+    era("正安", "しょうあん", None, (1299, 6, 1), (1302, 12, 17)),
+    era("乾元", "けんげん", None, (1302, 12, 18), (1303, 9, 23)),
+    era("嘉元", "かげん", None, (1303, 9, 24), (1307, 1, 25)),
+    era("徳治", "とくじ", None, (1307, 1, 26), (1308, 10, 30)),
+    era("延慶", "えんきょう", None, (1308, 10, 31), (1311, 5, 23)),
+    era("応長", "おうちょう", None, (1311, 5, 24), (1312, 5, 4)),
+    era("正和", "しょうわ", None, (1312, 5, 5), (1317, 3, 23)),
+    era("文保", "ぶんぽう", None, (1317, 3, 24), (1319, 5, 25)),
+    era("元応", "げんおう", None, (1319, 5, 26), (1321, 3, 29)),
+    era("元亨", "げんこう", None, (1321, 3, 30), (1325, 1, 1)),
+    era("正中", "しょうちゅう", None, (1325, 1, 2), (1326, 6, 4)),
+    era("嘉暦", "かりゃく", None, (1326, 6, 5), (1329, 9, 29)),
+    era("元徳", "げんとく", None, (1329, 9, 30), (1331, 9, 18)),
+    era("元弘", "げんこう", Some(Southern), (1331, 9, 19), (1334, 3, 12)),
+    // The Northern court continued to use 元徳 until 正慶.
+    era("元徳", "げんとく", Some(Northern), (1331, 9, 19), (1332, 5, 30)),
+    era("正慶", "しょうけい", Some(Northern), (1332, 5, 31), (1333, 7, 14)),
+    era("建武", "けんむ", None, (1334, 3, 13), (1338, 9, 19)),
+    era("延元", "えんげん", Some(Southern), (1336, 4, 19), (1340, 5, 31)),
+    era("暦応", "りゃくおう", Some(Northern), (1338, 9, 20), (1342, 6, 7)),
+    era("興国", "こうこく", Some(Southern), (1340, 6, 1), (1347, 1, 26)),
+    era("康永", "こうえい", Some(Northern), (1342, 6, 8), (1345, 11, 22)),
+    era("貞和", "じょうわ", Some(Northern), (1345, 11, 23), (1350, 4, 11)),
+    era("正平", "しょうへい", Some(Southern), (1347, 1, 27), (1370, 8, 22)),
+    era("観応", "かんのう", Some(Northern), (1350, 4, 12), (1352, 11, 11)),
+    era("文和", "ぶんな", Some(Northern), (1352, 11, 12), (1356, 5, 5)),
+    era("延文", "えんぶん", Some(Northern), (1356, 5, 6), (1361, 5, 11)),
+    era("康安", "こうあん", Some(Northern), (1361, 5, 12), (1362, 10, 18)),
+    era("貞治", "じょうじ", Some(Northern), (1362, 10, 19), (1368, 3, 14)),
+    era("応安", "おうあん", Some(Northern), (1368, 3, 15), (1375, 4, 5)),
+    era("建徳", "けんとく", Some(Southern), (1370, 8, 23), (1372, 5, 11)),
+    era("文中", "ぶんちゅう", Some(Southern), (1372, 5, 12), (1375, 7, 3)),
+    era("永和", "えいわ", Some(Northern), (1375, 4, 6), (1379, 4, 16)),
+    era("天授", "てんじゅ", Some(Southern), (1375, 7, 4), (1381, 3, 13)),
+    era("康暦", "こうりゃく", Some(Northern), (1379, 4, 17), (1381, 3, 27)),
+    era("弘和", "こうわ", Some(Southern), (1381, 3, 14), (1384, 5, 25)),
+    era("永徳", "えいとく", Some(Northern), (1381, 3, 28), (1384, 3, 26)),
+    era("至徳", "しとく", Some(Northern), (1384, 3, 27), (1387, 10, 12)),
+    era("元中", "げんちゅう", Some(Southern), (1384, 5, 26), (1392, 11, 26)),
+    era("嘉慶", "かけい", Some(Northern), (1387, 10, 13), (1389, 3, 13)),
+    era("康応", "こうおう", Some(Northern), (1389, 3, 14), (1390, 4, 18)),
+    era("明徳", "めいとく", Some(Northern), (1390, 4, 19), (1394, 8, 8)),
+    era("応永", "おうえい", None, (1394, 8, 9), (1428, 6, 17)),
+    era("正長", "しょうちょう", None, (1428, 6, 18), (1429, 10, 10)),
+    era("永享", "えいきょう", None, (1429, 10, 11), (1441, 3, 17)),
+    era("嘉吉", "かきつ", None, (1441, 3, 18), (1444, 3, 2)),
+    era("文安", "ぶんあん", None, (1444, 3, 3), (1449, 8, 24)),
+    era("宝徳", "ほうとく", None, (1449, 8, 25), (1452, 8, 18)),
+    era("享徳", "きょうとく", None, (1452, 8, 19), (1455, 9, 14)),
+    era("康正", "こうしょう", None, (1455, 9, 15), (1457, 10, 24)),
+    era("長禄", "ちょうろく", None, (1457, 10, 25), (1461, 2, 9)),
+    era("寛正", "かんしょう", None, (1461, 2, 10), (1466, 3, 22)),
+    era("文正", "ぶんしょう", None, (1466, 3, 23), (1467, 4, 14)),
+    era("応仁", "おうにん", None, (1467, 4, 15), (1469, 6, 16)),
+    era("文明", "ぶんめい", None, (1469, 6, 17), (1487, 8, 25)),
+    era("長享", "ちょうきょう", None, (1487, 8, 26), (1489, 9, 23)),
+    era("延徳", "えんとく", None, (1489, 9, 24), (1492, 8, 19)),
+    era("明応", "めいおう", None, (1492, 8, 20), (1501, 3, 27)),
+    era("文亀", "ぶんき", None, (1501, 3, 28), (1504, 3, 25)),
+    era("永正", "えいしょう", None, (1504, 3, 26), (1521, 10, 2)),
+    era("大永", "たいえい", None, (1521, 10, 3), (1528, 9, 12)),
+    era("享禄", "きょうろく", None, (1528, 9, 13), (1532, 9, 7)),
+    era("天文", "てんぶん", None, (1532, 9, 8), (1555, 11, 15)),
+    era("弘治", "こうじ", None, (1555, 11, 16), (1558, 3, 26)),
+    era("永禄", "えいろく", None, (1558, 3, 27), (1570, 6, 5)),
+    era("元亀", "げんき", None, (1570, 6, 6), (1573, 9, 3)),
+    era("天正", "てんしょう", None, (1573, 9, 4), (1593, 1, 9)),
+    era("文禄", "ぶんろく", None, (1593, 1, 10), (1596, 12, 15)),
+    era("慶長", "けいちょう", None, (1596, 12, 16), (1615, 8, 6)),
+    era("元和", "げんな", None, (1615, 8, 7), (1624, 4, 16)),
+    era("寛永", "かんえい", None, (1624, 4, 17), (1645, 1, 12)),
+    era("正保", "しょうほう", None, (1645, 1, 13), (1648, 3, 7)),
+    era("慶安", "けいあん", None, (1648, 3, 8), (1652, 10, 19)),
+    era("承応", "じょうおう", None, (1652, 10, 20), (1655, 5, 17)),
+    era("明暦", "めいれき", None, (1655, 5, 18), (1658, 8, 20)),
+    era("万治", "まんじ", None, (1658, 8, 21), (1661, 5, 22)),
+    era("寛文", "かんぶん", None, (1661, 5, 23), (1673, 10, 29)),
+    era("延宝", "えんぽう", None, (1673, 10, 30), (1681, 11, 8)),
+    era("天和", "てんな", None, (1681, 11, 9), (1684, 4, 4)),
+    era("貞享", "じょうきょう", None, (1684, 4, 5), (1688, 10, 22)),
+    era("元禄", "げんろく", None, (1688, 10, 23), (1704, 4, 15)),
+    era("宝永", "ほうえい", None, (1704, 4, 16), (1711, 6, 10)),
+    era("正徳", "しょうとく", None, (1711, 6, 11), (1716, 8, 8)),
+    era("享保", "きょうほう", None, (1716, 8, 9), (1736, 6, 6)),
+    era("元文", "げんぶん", None, (1736, 6, 7), (1741, 4, 11)),
+    era("寛保", "かんぽう", None, (1741, 4, 12), (1744, 4, 2)),
+    era("延享", "えんきょう", None, (1744, 4, 3), (1748, 8, 4)),
+    era("寛延", "かんえん", None, (1748, 8, 5), (1751, 12, 13)),
+    era("宝暦", "ほうれき", None, (1751, 12, 14), (1764, 6, 29)),
+    era("明和", "めいわ", None, (1764, 6, 30), (1772, 12, 9)),
+    era("安永", "あんえい", None, (1772, 12, 10), (1781, 4, 24)),
+    era("天明", "てんめい", None, (1781, 4, 25), (1789, 2, 18)),
+    era("寛政", "かんせい", None, (1789, 2, 19), (1801, 3, 18)),
+    era("享和", "きょうわ", None, (1801, 3, 19), (1804, 3, 21)),
+    era("文化", "ぶんか", None, (1804, 3, 22), (1818, 5, 25)),
+    era("文政", "ぶんせい", None, (1818, 5, 26), (1831, 1, 22)),
+    era("天保", "てんぽう", None, (1831, 1, 23), (1845, 1, 8)),
+    era("弘化", "こうか", None, (1845, 1, 9), (1848, 3, 31)),
+    era("嘉永", "かえい", None, (1848, 4, 1), (1855, 1, 14)),
+    era("安政", "あんせい", None, (1855, 1, 15), (1860, 4, 7)),
+    era("万延", "まんえん", None, (1860, 4, 8), (1861, 3, 28)),
+    era("文久", "ぶんきゅう", None, (1861, 3, 29), (1864, 3, 26)),
+    era("元治", "げんじ", None, (1864, 3, 27), (1865, 4, 30)),
+    era("慶応", "けいおう", None, (1865, 5, 1), (1868, 10, 22)),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::Court::*;
+    use super::{Court, HistoricalEra};
+
+    use chrono::NaiveDate;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(645, 7, 20, "大化")]
+    #[case(650, 3, 25, "白雉")]
+    #[case(701, 5, 7, "大宝")]
+    #[case(1185, 9, 16, "文治")]
+    #[case(1600, 10, 21, "慶長")]
+    #[case(1868, 10, 22, "慶応")]
+    fn 年月日から元号を導出できる(
+        #[case] year: i32,
+        #[case] month: u32,
+        #[case] day: u32,
+        #[case] expect: &str,
+    ) {
+        let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        assert_eq!(expect, HistoricalEra::from_datelike(&date).unwrap().name());
+    }
+
+    #[rstest]
+    #[case(645, 7, 19)]
+    #[case(660, 1, 1)]
+    #[case(690, 1, 1)]
+    #[case(1868, 10, 23)]
+    fn 元号が定められていない期間は導出できない(
+        #[case] year: i32,
+        #[case] month: u32,
+        #[case] day: u32,
+    ) {
+        let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        assert!(HistoricalEra::from_datelike(&date).is_none());
+    }
+
+    #[rstest]
+    #[case(1332, 1, 1, Northern, "元徳")]
+    #[case(1332, 1, 1, Southern, "元弘")]
+    #[case(1332, 8, 1, Northern, "正慶")]
+    #[case(1332, 8, 1, Southern, "元弘")]
+    #[case(1337, 1, 1, Northern, "建武")]
+    #[case(1337, 1, 1, Southern, "延元")]
+    #[case(1380, 1, 1, Northern, "康暦")]
+    #[case(1380, 1, 1, Southern, "天授")]
+    #[case(1392, 11, 26, Southern, "元中")]
+    #[case(1392, 11, 27, Southern, "明徳")]
+    #[case(1392, 11, 27, Northern, "明徳")]
+    fn 南北朝時代は朝廷ごとに元号を導出できる(
+        #[case] year: i32,
+        #[case] month: u32,
+        #[case] day: u32,
+        #[case] court: Court,
+        #[case] expect: &str,
+    ) {
+        let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        let era = HistoricalEra::from_datelike_with_court(&date, court).unwrap();
+        assert_eq!(expect, era.name());
+    }
+
+    #[rstest]
+    #[case("大化", "たいか", None)]
+    #[case("天平感宝", "てんぴょうかんぽう", None)]
+    #[case("暦応", "りゃくおう", Some(Northern))]
+    #[case("元中", "げんちゅう", Some(Southern))]
+    #[case("慶応", "けいおう", None)]
+    fn 元号の名前から変換できる(
+        #[case] name: &str,
+        #[case] reading: &str,
+        #[case] court: Option<Court>,
+    ) {
+        let era = HistoricalEra::from_name(name).unwrap();
+        assert_eq!(name, era.name());
+        assert_eq!(reading, era.reading());
+        assert_eq!(court, era.court());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("明治")]
+    #[case("令和")]
+    fn 明治より前の元号の名前でなければ変換できない(#[case] name: &str) {
+        assert!(HistoricalEra::from_name(name).is_none());
+    }
+
+    #[rstest]
+    fn 北朝が継続した元徳は名前からは分裂前の元号に変換される() {
+        let era = HistoricalEra::from_name("元徳").unwrap();
+        let start = NaiveDate::from_ymd_opt(1329, 9, 30).unwrap();
+        let end = NaiveDate::from_ymd_opt(1331, 9, 18).unwrap();
+        assert_eq!(None, era.court());
+        assert_eq!(start, era.start_date());
+        assert_eq!(end, era.end_date());
+    }
+
+    #[rstest]
+    #[case("大化", (645, 7, 20), (650, 3, 24))]
+    #[case("建武", (1334, 3, 13), (1338, 9, 19))]
+    #[case("慶応", (1865, 5, 1), (1868, 10, 22))]
+    fn 元号の期間を導出できる(
+        #[case] name: &str,
+        #[case] start: (i32, u32, u32),
+        #[case] end: (i32, u32, u32),
+    ) {
+        let era = HistoricalEra::from_name(name).unwrap();
+        let start = NaiveDate::from_ymd_opt(start.0, start.1, start.2).unwrap();
+        let end = NaiveDate::from_ymd_opt(end.0, end.1, end.2).unwrap();
+        assert_eq!(start, era.start_date());
+        assert_eq!(end, era.end_date());
+    }
+
+    #[rstest]
+    fn 南北朝の名前に変換できる() {
+        assert_eq!("北朝", Northern.name());
+        assert_eq!("南朝", Southern.name());
+    }
+}
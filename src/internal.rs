@@ -1,3 +1,21 @@
+use chrono::{Datelike, NaiveDate};
+
+#[rustfmt::skip]
+pub(crate) const CHOKU: [(&str, &str); 12] = [
+    ("建", "たつ"),
+    ("除", "のぞく"),
+    ("満", "みつ"),
+    ("平", "たいら"),
+    ("定", "さだん"),
+    ("執", "とる"),
+    ("破", "やぶる"),
+    ("危", "あやぶ"),
+    ("成", "なる"),
+    ("納", "おさん"),
+    ("開", "ひらく"),
+    ("閉", "とづ"),
+];
+
 #[rustfmt::skip]
 pub(crate) const HEAVENLY_STEMS: [&str; 10] = [
     "甲",
@@ -12,6 +30,20 @@ pub(crate) const HEAVENLY_STEMS: [&str; 10] = [
     "癸"
 ];
 
+#[rustfmt::skip]
+pub(crate) const HEAVENLY_STEM_READINGS: [&str; 10] = [
+    "きのえ",
+    "きのと",
+    "ひのえ",
+    "ひのと",
+    "つちのえ",
+    "つちのと",
+    "かのえ",
+    "かのと",
+    "みずのえ",
+    "みずのと",
+];
+
 #[rustfmt::skip]
 pub(crate) const JAPANESE_MONTHS: [&str; 12] = [
     "睦月",
@@ -55,6 +87,187 @@ pub(crate) const JAPANESE_ZODIAC: [&str; 12] = [
     "亥",
 ];
 
+#[rustfmt::skip]
+pub(crate) const JAPANESE_ZODIAC_ANIMALS: [(&str, &str); 12] = [
+    ("鼠", "Rat"),
+    ("牛", "Ox"),
+    ("虎", "Tiger"),
+    ("兎", "Rabbit"),
+    ("竜", "Dragon"),
+    ("蛇", "Snake"),
+    ("馬", "Horse"),
+    ("羊", "Sheep"),
+    ("猿", "Monkey"),
+    ("鶏", "Rooster"),
+    ("犬", "Dog"),
+    ("猪", "Boar"),
+];
+
+#[rustfmt::skip]
+pub(crate) const JAPANESE_ZODIAC_DIRECTIONS: [&str; 12] = [
+    "北",
+    "北北東",
+    "東北東",
+    "東",
+    "東南東",
+    "南南東",
+    "南",
+    "南南西",
+    "西南西",
+    "西",
+    "西北西",
+    "北北西",
+];
+
+#[rustfmt::skip]
+pub(crate) const JAPANESE_ZODIAC_READINGS: [&str; 12] = [
+    "ね",
+    "うし",
+    "とら",
+    "う",
+    "たつ",
+    "み",
+    "うま",
+    "ひつじ",
+    "さる",
+    "とり",
+    "いぬ",
+    "い",
+];
+
+#[rustfmt::skip]
+pub(crate) const KOU: [(&str, &str); 72] = [
+    ("東風解凍", "はるかぜこおりをとく"),
+    ("黄鶯睍睆", "うぐいすなく"),
+    ("魚上氷", "うおこおりをいずる"),
+    ("土脉潤起", "つちのしょううるおいおこる"),
+    ("霞始靆", "かすみはじめてたなびく"),
+    ("草木萌動", "そうもくめばえいずる"),
+    ("蟄虫啓戸", "すごもりむしとをひらく"),
+    ("桃始笑", "ももはじめてさく"),
+    ("菜虫化蝶", "なむしちょうとなる"),
+    ("雀始巣", "すずめはじめてすくう"),
+    ("櫻始開", "さくらはじめてひらく"),
+    ("雷乃発声", "かみなりすなわちこえをはっす"),
+    ("玄鳥至", "つばめきたる"),
+    ("鴻雁北", "こうがんかえる"),
+    ("虹始見", "にじはじめてあらわる"),
+    ("葭始生", "あしはじめてしょうず"),
+    ("霜止出苗", "しもやみてなえいずる"),
+    ("牡丹華", "ぼたんはなさく"),
+    ("蛙始鳴", "かわずはじめてなく"),
+    ("蚯蚓出", "みみずいずる"),
+    ("竹笋生", "たけのこしょうず"),
+    ("蚕起食桑", "かいこおきてくわをはむ"),
+    ("紅花栄", "べにばなさかう"),
+    ("麦秋至", "むぎのときいたる"),
+    ("螳螂生", "かまきりしょうず"),
+    ("腐草為螢", "くされたるくさほたるとなる"),
+    ("梅子黄", "うめのみきばむ"),
+    ("乃東枯", "なつかれくさかるる"),
+    ("菖蒲華", "あやめはなさく"),
+    ("半夏生", "はんげしょうず"),
+    ("温風至", "あつかぜいたる"),
+    ("蓮始開", "はすはじめてひらく"),
+    ("鷹乃学習", "たかすなわちわざをならう"),
+    ("桐始結花", "きりはじめてはなをむすぶ"),
+    ("土潤溽暑", "つちうるおうてむしあつし"),
+    ("大雨時行", "たいうときどきふる"),
+    ("涼風至", "すずかぜいたる"),
+    ("寒蝉鳴", "ひぐらしなく"),
+    ("蒙霧升降", "ふかききりまとう"),
+    ("綿柎開", "わたのはなしべひらく"),
+    ("天地始粛", "てんちはじめてさむし"),
+    ("禾乃登", "こくものすなわちみのる"),
+    ("草露白", "くさのつゆしろし"),
+    ("鶺鴒鳴", "せきれいなく"),
+    ("玄鳥去", "つばめさる"),
+    ("雷乃収声", "かみなりすなわちこえをおさむ"),
+    ("蟄虫坏戸", "むしかくれてとをふさぐ"),
+    ("水始涸", "みずはじめてかるる"),
+    ("鴻雁来", "こうがんきたる"),
+    ("菊花開", "きくのはなひらく"),
+    ("蟋蟀在戸", "きりぎりすとにあり"),
+    ("霜始降", "しもはじめてふる"),
+    ("霎時施", "こさめときどきふる"),
+    ("楓蔦黄", "もみじつたきばむ"),
+    ("山茶始開", "つばきはじめてひらく"),
+    ("地始凍", "ちはじめてこおる"),
+    ("金盞香", "きんせんかさく"),
+    ("虹蔵不見", "にじかくれてみえず"),
+    ("朔風払葉", "きたかぜこのはをはらう"),
+    ("橘始黄", "たちばなはじめてきばむ"),
+    ("閉塞成冬", "そらさむくふゆとなる"),
+    ("熊蟄穴", "くまあなにこもる"),
+    ("鱖魚群", "さけのうおむらがる"),
+    ("乃東生", "なつかれくさしょうず"),
+    ("麋角解", "さわしかつのおつる"),
+    ("雪下出麦", "ゆきわたりてむぎのびる"),
+    ("芹乃栄", "せりすなわちさかう"),
+    ("水泉動", "しみずあたたかをふくむ"),
+    ("雉始雊", "きじはじめてなく"),
+    ("款冬華", "ふきのはなさく"),
+    ("水沢腹堅", "さわみずこおりつめる"),
+    ("鶏始乳", "にわとりはじめてとやにつく"),
+];
+
+#[rustfmt::skip]
+pub(crate) const NACCHIN: [(&str, &str); 30] = [
+    ("海中金", "かいちゅうきん"),
+    ("炉中火", "ろちゅうか"),
+    ("大林木", "だいりんぼく"),
+    ("路傍土", "ろぼうど"),
+    ("剣鋒金", "じんぽうきん"),
+    ("山頭火", "さんとうか"),
+    ("澗下水", "かんかすい"),
+    ("城頭土", "じょうとうど"),
+    ("白鑞金", "はくろうきん"),
+    ("楊柳木", "ようりゅうぼく"),
+    ("井泉水", "せいせんすい"),
+    ("屋上土", "おくじょうど"),
+    ("霹靂火", "へきれきか"),
+    ("松柏木", "しょうはくぼく"),
+    ("長流水", "ちょうりゅうすい"),
+    ("沙中金", "さちゅうきん"),
+    ("山下火", "さんげか"),
+    ("平地木", "へいちぼく"),
+    ("壁上土", "へきじょうど"),
+    ("金箔金", "きんぱくきん"),
+    ("覆燈火", "ふくとうか"),
+    ("天河水", "てんがすい"),
+    ("大駅土", "たいえきど"),
+    ("釵釧金", "させんきん"),
+    ("桑柘木", "そうしゃくぼく"),
+    ("大渓水", "だいけいすい"),
+    ("沙中土", "さちゅうど"),
+    ("天上火", "てんじょうか"),
+    ("柘榴木", "ざくろぼく"),
+    ("大海水", "たいかいすい"),
+];
+
+#[rustfmt::skip]
+pub(crate) const NINE_STARS: [(&str, &str); 9] = [
+    ("一白水星", "いっぱくすいせい"),
+    ("二黒土星", "じこくどせい"),
+    ("三碧木星", "さんぺきもくせい"),
+    ("四緑木星", "しろくもくせい"),
+    ("五黄土星", "ごおうどせい"),
+    ("六白金星", "ろっぱくきんせい"),
+    ("七赤金星", "しちせききんせい"),
+    ("八白土星", "はっぱくどせい"),
+    ("九紫火星", "きゅうしかせい"),
+];
+
+#[rustfmt::skip]
+pub(crate) const ROKUYO: [(&str, &str); 6] = [
+    ("先勝", "せんしょう"),
+    ("友引", "ともびき"),
+    ("先負", "せんぶ"),
+    ("仏滅", "ぶつめつ"),
+    ("大安", "たいあん"),
+    ("赤口", "しゃっこう"),
+];
+
 #[rustfmt::skip]
 pub(crate) const SEXAGENARY_CYCLE: [&str; 60] = [
     "甲子", "乙丑", "丙寅", "丁卯", "戊辰", "己巳", "庚午", "辛未", "壬申", "癸酉", "甲戌", "乙亥",
@@ -64,9 +277,94 @@ pub(crate) const SEXAGENARY_CYCLE: [&str; 60] = [
     "壬子", "癸丑", "甲寅", "乙卯", "丙辰", "丁巳", "戊午", "己未", "庚申", "辛酉", "壬戌", "癸亥",
 ];
 
-#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[rustfmt::skip]
+pub(crate) const SEXAGENARY_CYCLE_READINGS: [&str; 60] = [
+    "きのえね", "きのとうし", "ひのえとら", "ひのとう", "つちのえたつ", "つちのとみ",
+    "かのえうま", "かのとひつじ", "みずのえさる", "みずのととり", "きのえいぬ", "きのとい",
+    "ひのえね", "ひのとうし", "つちのえとら", "つちのとう", "かのえたつ", "かのとみ",
+    "みずのえうま", "みずのとひつじ", "きのえさる", "きのととり", "ひのえいぬ", "ひのとい",
+    "つちのえね", "つちのとうし", "かのえとら", "かのとう", "みずのえたつ", "みずのとみ",
+    "きのえうま", "きのとひつじ", "ひのえさる", "ひのととり", "つちのえいぬ", "つちのとい",
+    "かのえね", "かのとうし", "みずのえとら", "みずのとう", "きのえたつ", "きのとみ",
+    "ひのえうま", "ひのとひつじ", "つちのえさる", "つちのととり", "かのえいぬ", "かのとい",
+    "みずのえね", "みずのとうし", "きのえとら", "きのとう", "ひのえたつ", "ひのとみ",
+    "つちのえうま", "つちのとひつじ", "かのえさる", "かのととり", "みずのえいぬ", "みずのとい",
+];
+
+#[rustfmt::skip]
+pub(crate) const SHUKU: [(&str, &str); 28] = [
+    ("角", "かく"),
+    ("亢", "こう"),
+    ("氐", "てい"),
+    ("房", "ぼう"),
+    ("心", "しん"),
+    ("尾", "び"),
+    ("箕", "き"),
+    ("斗", "と"),
+    ("牛", "ぎゅう"),
+    ("女", "じょ"),
+    ("虚", "きょ"),
+    ("危", "き"),
+    ("室", "しつ"),
+    ("壁", "へき"),
+    ("奎", "けい"),
+    ("婁", "ろう"),
+    ("胃", "い"),
+    ("昴", "ぼう"),
+    ("畢", "ひつ"),
+    ("觜", "し"),
+    ("参", "しん"),
+    ("井", "せい"),
+    ("鬼", "き"),
+    ("柳", "りゅう"),
+    ("星", "せい"),
+    ("張", "ちょう"),
+    ("翼", "よく"),
+    ("軫", "しん"),
+];
+
+#[rustfmt::skip]
+pub(crate) const SOLAR_TERMS: [(&str, &str); 24] = [
+    ("立春", "りっしゅん"),
+    ("雨水", "うすい"),
+    ("啓蟄", "けいちつ"),
+    ("春分", "しゅんぶん"),
+    ("清明", "せいめい"),
+    ("穀雨", "こくう"),
+    ("立夏", "りっか"),
+    ("小満", "しょうまん"),
+    ("芒種", "ぼうしゅ"),
+    ("夏至", "げし"),
+    ("小暑", "しょうしょ"),
+    ("大暑", "たいしょ"),
+    ("立秋", "りっしゅう"),
+    ("処暑", "しょしょ"),
+    ("白露", "はくろ"),
+    ("秋分", "しゅうぶん"),
+    ("寒露", "かんろ"),
+    ("霜降", "そうこう"),
+    ("立冬", "りっとう"),
+    ("小雪", "しょうせつ"),
+    ("大雪", "たいせつ"),
+    ("冬至", "とうじ"),
+    ("小寒", "しょうかん"),
+    ("大寒", "だいかん"),
+];
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub(crate) struct InternalDate {
     pub year: i32,
     pub month: u32,
     pub day: u32,
 }
+
+/// Returns the index of the sexagenary cycle of the day, counted from `0` (甲子).
+pub(crate) fn day_cycle_index<T: Datelike>(date: &T) -> usize {
+    // 2024-01-01 is 甲子.
+    (date.num_days_from_ce() + 14).rem_euclid(60) as usize
+}
+
+/// Converts Datelike of chrono into NaiveDate.
+pub(crate) fn to_naive_date<T: Datelike>(date: &T) -> NaiveDate {
+    NaiveDate::from_ymd_opt(date.year(), date.month(), date.day()).unwrap()
+}
@@ -0,0 +1,514 @@
+use crate::astronomy::{jde_from_date, jst_from_jde, solar_longitude, solar_longitude_jde};
+use crate::internal::{KOU, to_naive_date};
+use crate::solar_term::SolarTerm;
+use chrono::{Datelike, NaiveDateTime};
+
+use self::Kou::*;
+
+/// Seventy-two pentads (七十二候)
+///
+/// Each solar term is divided into three pentads (初候, 次候 and 末候) of about five days,
+/// that is, every 5 degrees of the apparent solar longitude starting from 東風解凍 (315 degrees).
+/// The names follow 略本暦 published by the National Astronomical Observatory of Japan.
+///
+/// You can convert between the order of the pentads,
+/// noting that it starts from `1` (東風解凍).
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Kou {
+    /// 東風解凍
+    Harukazekooriwotoku,
+    /// 黄鶯睍睆
+    Uguisunaku,
+    /// 魚上氷
+    Uokooriwoizuru,
+    /// 土脉潤起
+    Tsuchinoshouuruoiokoru,
+    /// 霞始靆
+    Kasumihajimetetanabiku,
+    /// 草木萌動
+    Soumokumebaeizuru,
+    /// 蟄虫啓戸
+    Sugomorimushitowohiraku,
+    /// 桃始笑
+    Momohajimetesaku,
+    /// 菜虫化蝶
+    Namushichoutonaru,
+    /// 雀始巣
+    Suzumehajimetesukuu,
+    /// 櫻始開
+    Sakurahajimetehiraku,
+    /// 雷乃発声
+    Kaminarisunawachikoewohassu,
+    /// 玄鳥至
+    Tsubamekitaru,
+    /// 鴻雁北
+    Kougankaeru,
+    /// 虹始見
+    Nijihajimetearawaru,
+    /// 葭始生
+    Ashihajimeteshouzu,
+    /// 霜止出苗
+    Shimoyamitenaeizuru,
+    /// 牡丹華
+    Botanhanasaku,
+    /// 蛙始鳴
+    Kawazuhajimetenaku,
+    /// 蚯蚓出
+    Mimizuizuru,
+    /// 竹笋生
+    Takenokoshouzu,
+    /// 蚕起食桑
+    Kaikookitekuwawohamu,
+    /// 紅花栄
+    Benibanasakau,
+    /// 麦秋至
+    Muginotokiitaru,
+    /// 螳螂生
+    Kamakirishouzu,
+    /// 腐草為螢
+    Kusaretarukusahotarutonaru,
+    /// 梅子黄
+    Umenomikibamu,
+    /// 乃東枯
+    Natsukarekusakaruru,
+    /// 菖蒲華
+    Ayamehanasaku,
+    /// 半夏生
+    Hangeshouzu,
+    /// 温風至
+    Atsukazeitaru,
+    /// 蓮始開
+    Hasuhajimetehiraku,
+    /// 鷹乃学習
+    Takasunawachiwazawonarau,
+    /// 桐始結花
+    Kirihajimetehanawomusubu,
+    /// 土潤溽暑
+    Tsuchiuruoutemushiatsushi,
+    /// 大雨時行
+    Taiutokidokifuru,
+    /// 涼風至
+    Suzukazeitaru,
+    /// 寒蝉鳴
+    Higurashinaku,
+    /// 蒙霧升降
+    Fukakikirimatou,
+    /// 綿柎開
+    Watanohanashibehiraku,
+    /// 天地始粛
+    Tenchihajimetesamushi,
+    /// 禾乃登
+    Kokumonosunawachiminoru,
+    /// 草露白
+    Kusanotsuyushiroshi,
+    /// 鶺鴒鳴
+    Sekireinaku,
+    /// 玄鳥去
+    Tsubamesaru,
+    /// 雷乃収声
+    Kaminarisunawachikoewoosamu,
+    /// 蟄虫坏戸
+    Mushikakuretetowofusagu,
+    /// 水始涸
+    Mizuhajimetekaruru,
+    /// 鴻雁来
+    Kougankitaru,
+    /// 菊花開
+    Kikunohanahiraku,
+    /// 蟋蟀在戸
+    Kirigirisutoniari,
+    /// 霜始降
+    Shimohajimetefuru,
+    /// 霎時施
+    Kosametokidokifuru,
+    /// 楓蔦黄
+    Momijitsutakibamu,
+    /// 山茶始開
+    Tsubakihajimetehiraku,
+    /// 地始凍
+    Chihajimetekooru,
+    /// 金盞香
+    Kinsenkasaku,
+    /// 虹蔵不見
+    Nijikakuretemiezu,
+    /// 朔風払葉
+    Kitakazekonohawoharau,
+    /// 橘始黄
+    Tachibanahajimetekibamu,
+    /// 閉塞成冬
+    Sorasamukufuyutonaru,
+    /// 熊蟄穴
+    Kumaananikomoru,
+    /// 鱖魚群
+    Sakenouomuragaru,
+    /// 乃東生
+    Natsukarekusashouzu,
+    /// 麋角解
+    Sawashikatsunootsuru,
+    /// 雪下出麦
+    Yukiwataritemuginobiru,
+    /// 芹乃栄
+    Serisunawachisakau,
+    /// 水泉動
+    Shimizuatatakawofukumu,
+    /// 雉始雊
+    Kijihajimetenaku,
+    /// 款冬華
+    Fukinohanasaku,
+    /// 水沢腹堅
+    Sawamizukooritsumeru,
+    /// 鶏始乳
+    Niwatorihajimetetoyanitsuku,
+}
+
+impl Kou {
+    /// Returns the date and time in JST when the pentad begins in the specified year.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::Kou;
+    ///
+    /// let kou = Kou::from_name("櫻始開").unwrap();
+    ///
+    /// assert_eq!(
+    ///     NaiveDate::from_ymd_opt(2024, 3, 25).unwrap(),
+    ///     kou.datetime(2024).unwrap().date()
+    /// );
+    /// ```
+    pub fn datetime(&self, year: i32) -> Option<NaiveDateTime> {
+        solar_longitude_jde(year, self.longitude() as f64).and_then(jst_from_jde)
+    }
+
+    /// Generate from Datelike of chrono.
+    ///
+    /// Returns the pentad which the date belongs to.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::Kou;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 2, 4).unwrap();
+    /// assert_eq!("東風解凍", Kou::from_datelike(&date).name());
+    /// ```
+    pub fn from_datelike<T: Datelike>(date: &T) -> Self {
+        let date = to_naive_date(date);
+        let longitude = solar_longitude(jde_from_date(&date) + 1.0);
+
+        // The index is counted from 雀始巣 (0 degrees), which is the 10th pentad.
+        Kou::from_number(((longitude / 5.0).floor() as usize + 9) % 72 + 1).unwrap()
+    }
+
+    /// Generate from name of pentad.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Kou;
+    ///
+    /// assert_eq!(Some(Kou::Niwatorihajimetetoyanitsuku), Kou::from_name("鶏始乳"));
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        KOU.iter()
+            .position(|&(x, _)| x == name)
+            .and_then(|i| Kou::from_number(i + 1))
+    }
+
+    /// Generate from number of pentad.
+    /// The index starts from `1` (東風解凍).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Kou;
+    ///
+    /// assert_eq!(Some(Kou::Harukazekooriwotoku), Kou::from_number(1));
+    /// assert!(Kou::from_number(73).is_none());
+    /// ```
+    pub const fn from_number(num: usize) -> Option<Self> {
+        match num {
+            1 => Some(Harukazekooriwotoku),
+            2 => Some(Uguisunaku),
+            3 => Some(Uokooriwoizuru),
+            4 => Some(Tsuchinoshouuruoiokoru),
+            5 => Some(Kasumihajimetetanabiku),
+            6 => Some(Soumokumebaeizuru),
+            7 => Some(Sugomorimushitowohiraku),
+            8 => Some(Momohajimetesaku),
+            9 => Some(Namushichoutonaru),
+            10 => Some(Suzumehajimetesukuu),
+            11 => Some(Sakurahajimetehiraku),
+            12 => Some(Kaminarisunawachikoewohassu),
+            13 => Some(Tsubamekitaru),
+            14 => Some(Kougankaeru),
+            15 => Some(Nijihajimetearawaru),
+            16 => Some(Ashihajimeteshouzu),
+            17 => Some(Shimoyamitenaeizuru),
+            18 => Some(Botanhanasaku),
+            19 => Some(Kawazuhajimetenaku),
+            20 => Some(Mimizuizuru),
+            21 => Some(Takenokoshouzu),
+            22 => Some(Kaikookitekuwawohamu),
+            23 => Some(Benibanasakau),
+            24 => Some(Muginotokiitaru),
+            25 => Some(Kamakirishouzu),
+            26 => Some(Kusaretarukusahotarutonaru),
+            27 => Some(Umenomikibamu),
+            28 => Some(Natsukarekusakaruru),
+            29 => Some(Ayamehanasaku),
+            30 => Some(Hangeshouzu),
+            31 => Some(Atsukazeitaru),
+            32 => Some(Hasuhajimetehiraku),
+            33 => Some(Takasunawachiwazawonarau),
+            34 => Some(Kirihajimetehanawomusubu),
+            35 => Some(Tsuchiuruoutemushiatsushi),
+            36 => Some(Taiutokidokifuru),
+            37 => Some(Suzukazeitaru),
+            38 => Some(Higurashinaku),
+            39 => Some(Fukakikirimatou),
+            40 => Some(Watanohanashibehiraku),
+            41 => Some(Tenchihajimetesamushi),
+            42 => Some(Kokumonosunawachiminoru),
+            43 => Some(Kusanotsuyushiroshi),
+            44 => Some(Sekireinaku),
+            45 => Some(Tsubamesaru),
+            46 => Some(Kaminarisunawachikoewoosamu),
+            47 => Some(Mushikakuretetowofusagu),
+            48 => Some(Mizuhajimetekaruru),
+            49 => Some(Kougankitaru),
+            50 => Some(Kikunohanahiraku),
+            51 => Some(Kirigirisutoniari),
+            52 => Some(Shimohajimetefuru),
+            53 => Some(Kosametokidokifuru),
+            54 => Some(Momijitsutakibamu),
+            55 => Some(Tsubakihajimetehiraku),
+            56 => Some(Chihajimetekooru),
+            57 => Some(Kinsenkasaku),
+            58 => Some(Nijikakuretemiezu),
+            59 => Some(Kitakazekonohawoharau),
+            60 => Some(Tachibanahajimetekibamu),
+            61 => Some(Sorasamukufuyutonaru),
+            62 => Some(Kumaananikomoru),
+            63 => Some(Sakenouomuragaru),
+            64 => Some(Natsukarekusashouzu),
+            65 => Some(Sawashikatsunootsuru),
+            66 => Some(Yukiwataritemuginobiru),
+            67 => Some(Serisunawachisakau),
+            68 => Some(Shimizuatatakawofukumu),
+            69 => Some(Kijihajimetenaku),
+            70 => Some(Fukinohanasaku),
+            71 => Some(Sawamizukooritsumeru),
+            72 => Some(Niwatorihajimetetoyanitsuku),
+            _ => None,
+        }
+    }
+
+    /// Returns the apparent solar longitude in degrees when the pentad begins.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Kou;
+    ///
+    /// assert_eq!(315, Kou::Harukazekooriwotoku.longitude());
+    /// ```
+    pub const fn longitude(&self) -> u32 {
+        ((self.to_number() as u32 - 1) * 5 + 315) % 360
+    }
+
+    /// Returns the name of pentad.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Kou;
+    ///
+    /// assert_eq!("東風解凍", Kou::Harukazekooriwotoku.name());
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        KOU[self.to_number() - 1].0
+    }
+
+    /// Returns the reading of pentad in hiragana.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Kou;
+    ///
+    /// assert_eq!("はるかぜこおりをとく", Kou::Harukazekooriwotoku.reading());
+    /// ```
+    pub const fn reading(&self) -> &'static str {
+        KOU[self.to_number() - 1].1
+    }
+
+    /// Returns the solar term which the pentad belongs to.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::{Kou, SolarTerm};
+    ///
+    /// assert_eq!(SolarTerm::Risshun, Kou::Uokooriwoizuru.solar_term());
+    /// ```
+    pub const fn solar_term(&self) -> SolarTerm {
+        match SolarTerm::from_number((self.to_number() - 1) / 3 + 1) {
+            Some(term) => term,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns the number of pentad.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Kou;
+    ///
+    /// assert_eq!(72, Kou::Niwatorihajimetetoyanitsuku.to_number());
+    /// ```
+    pub const fn to_number(&self) -> usize {
+        match self {
+            Harukazekooriwotoku => 1,
+            Uguisunaku => 2,
+            Uokooriwoizuru => 3,
+            Tsuchinoshouuruoiokoru => 4,
+            Kasumihajimetetanabiku => 5,
+            Soumokumebaeizuru => 6,
+            Sugomorimushitowohiraku => 7,
+            Momohajimetesaku => 8,
+            Namushichoutonaru => 9,
+            Suzumehajimetesukuu => 10,
+            Sakurahajimetehiraku => 11,
+            Kaminarisunawachikoewohassu => 12,
+            Tsubamekitaru => 13,
+            Kougankaeru => 14,
+            Nijihajimetearawaru => 15,
+            Ashihajimeteshouzu => 16,
+            Shimoyamitenaeizuru => 17,
+            Botanhanasaku => 18,
+            Kawazuhajimetenaku => 19,
+            Mimizuizuru => 20,
+            Takenokoshouzu => 21,
+            Kaikookitekuwawohamu => 22,
+            Benibanasakau => 23,
+            Muginotokiitaru => 24,
+            Kamakirishouzu => 25,
+            Kusaretarukusahotarutonaru => 26,
+            Umenomikibamu => 27,
+            Natsukarekusakaruru => 28,
+            Ayamehanasaku => 29,
+            Hangeshouzu => 30,
+            Atsukazeitaru => 31,
+            Hasuhajimetehiraku => 32,
+            Takasunawachiwazawonarau => 33,
+            Kirihajimetehanawomusubu => 34,
+            Tsuchiuruoutemushiatsushi => 35,
+            Taiutokidokifuru => 36,
+            Suzukazeitaru => 37,
+            Higurashinaku => 38,
+            Fukakikirimatou => 39,
+            Watanohanashibehiraku => 40,
+            Tenchihajimetesamushi => 41,
+            Kokumonosunawachiminoru => 42,
+            Kusanotsuyushiroshi => 43,
+            Sekireinaku => 44,
+            Tsubamesaru => 45,
+            Kaminarisunawachikoewoosamu => 46,
+            Mushikakuretetowofusagu => 47,
+            Mizuhajimetekaruru => 48,
+            Kougankitaru => 49,
+            Kikunohanahiraku => 50,
+            Kirigirisutoniari => 51,
+            Shimohajimetefuru => 52,
+            Kosametokidokifuru => 53,
+            Momijitsutakibamu => 54,
+            Tsubakihajimetehiraku => 55,
+            Chihajimetekooru => 56,
+            Kinsenkasaku => 57,
+            Nijikakuretemiezu => 58,
+            Kitakazekonohawoharau => 59,
+            Tachibanahajimetekibamu => 60,
+            Sorasamukufuyutonaru => 61,
+            Kumaananikomoru => 62,
+            Sakenouomuragaru => 63,
+            Natsukarekusashouzu => 64,
+            Sawashikatsunootsuru => 65,
+            Yukiwataritemuginobiru => 66,
+            Serisunawachisakau => 67,
+            Shimizuatatakawofukumu => 68,
+            Kijihajimetenaku => 69,
+            Fukinohanasaku => 70,
+            Sawamizukooritsumeru => 71,
+            Niwatorihajimetetoyanitsuku => 72,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::NaiveDate;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1, 29, "水沢腹堅")]
+    #[case(1, 30, "鶏始乳")]
+    #[case(2, 3, "鶏始乳")]
+    #[case(2, 4, "東風解凍")]
+    #[case(2, 8, "東風解凍")]
+    #[case(2, 9, "黄鶯睍睆")]
+    #[case(3, 20, "雀始巣")]
+    #[case(3, 25, "櫻始開")]
+    #[case(10, 17, "菊花開")]
+    #[case(10, 18, "蟋蟀在戸")]
+    fn 年月日から変換できる(#[case] m: u32, #[case] d: u32, #[case] expect: &str) {
+        let date = NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+
+        assert_eq!(expect, Kou::from_datelike(&date).name());
+    }
+
+    #[rstest]
+    fn 名前と読みと番号を相互に変換できる() {
+        for num in 1..=72 {
+            let kou = Kou::from_number(num).unwrap();
+
+            assert_eq!(num, kou.to_number());
+            assert_eq!(Some(kou), Kou::from_name(kou.name()));
+            assert!(!kou.reading().is_empty());
+        }
+        assert!(Kou::from_number(0).is_none());
+        assert!(Kou::from_number(73).is_none());
+        assert!(Kou::from_name("東風").is_none());
+    }
+
+    #[rstest]
+    #[case(1, SolarTerm::Risshun)]
+    #[case(10, SolarTerm::Shunbun)]
+    #[case(30, SolarTerm::Geshi)]
+    #[case(72, SolarTerm::Daikan)]
+    fn 候が属する節気を導出できる(#[case] num: usize, #[case] expect: SolarTerm) {
+        let kou = Kou::from_number(num).unwrap();
+
+        assert_eq!(expect, kou.solar_term());
+        assert_eq!(
+            expect.longitude(),
+            Kou::from_number((num - 1) / 3 * 3 + 1).unwrap().longitude()
+        );
+    }
+
+    #[rstest]
+    fn 候の始まりの日時を導出できる() {
+        let expect = NaiveDate::from_ymd_opt(2024, 2, 9)
+            .unwrap()
+            .and_hms_opt(15, 49, 0)
+            .unwrap();
+        let datetime = Kou::from_number(2).unwrap().datetime(2024).unwrap();
+
+        assert!((datetime - expect).num_seconds().abs() < 60);
+    }
+}
@@ -1,25 +1,44 @@
 use std::iter::Iterator;
 
+use crate::choku::Choku;
 use crate::day::{JapaneseHoliday, JapaneseWeekday};
 use crate::era::JapaneseEra;
+use crate::format::JapaneseDateFormat;
+use crate::kou::Kou;
+use crate::lunisolar::{LunisolarDate, LunisolarYear};
 use crate::month::JapaneseMonth;
+use crate::provider::{Holiday, HolidayProvider, NationalHolidays};
+use crate::rokuyo::Rokuyo;
+use crate::senjitsu::Senjitsu;
+use crate::shuku::Shuku;
+use crate::solar_term::SolarTerm;
 use crate::year::{HeavenlyStem, JapaneseZodiac, SexagenaryCycle};
+use crate::zassetsu::Zassetsu;
 
+use crate::internal::to_naive_date;
 use chrono::{Datelike, Local, Months, NaiveDate};
 
 /// Japanese date
 ///
 /// It includes dates with Japanese-specific definitions as well.
 /// You can generate it independently, but it's also intended to be used as a [`Koyomi`] item.
-#[derive(Debug, Eq, PartialEq)]
-pub struct JapaneseDate {
+///
+/// The holiday is provided by a [`HolidayProvider`], Japanese national holidays by default.
+///
+/// The definitions calculated astronomically, such as the solar terms and the lunisolar date,
+/// are calculated from the date each time they are requested.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JapaneseDate<H = JapaneseHoliday> {
+    date: NaiveDate,
     day: u32,
+    day_sexagenary_cycle: SexagenaryCycle,
     era: Option<JapaneseEra>,
     heavenly_stem: HeavenlyStem,
-    holiday: Option<JapaneseHoliday>,
+    holiday: Option<H>,
     month: JapaneseMonth,
     month_number: u32,
     sexagenary_cycle: SexagenaryCycle,
+    shuku: Shuku,
     weekday: JapaneseWeekday,
     western_year: i32,
     zodiac: JapaneseZodiac,
@@ -40,20 +59,124 @@ impl JapaneseDate {
     /// assert!(japanese_date.is_holiday());
     /// ```
     pub fn from_datelike<T: Datelike>(date: &T) -> Self {
+        Self::from_datelike_with_holidays(date, &NationalHolidays)
+    }
+
+    /// Returns the name of holiday.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseDate;
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    ///
+    /// assert_eq!("元日", japanese_date.holiday_name().unwrap());
+    /// ```
+    pub const fn holiday_name(&self) -> Option<&'static str> {
+        match self.holiday {
+            Some(holiday) => Some(holiday.name()),
+            None => None,
+        }
+    }
+}
+
+impl<H: Holiday> JapaneseDate<H> {
+    /// Generate from Datelike of chrono with the holidays of the provider.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{HolidayCalendar, JapaneseDate};
+    ///
+    /// let calendar = HolidayCalendar::new().with_fixed_date("創立記念日", 7, 1);
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike_with_holidays(&chrono_date, &calendar);
+    ///
+    /// assert!(japanese_date.is_holiday());
+    /// assert_eq!(Some("創立記念日"), japanese_date.custom_holiday_name());
+    /// ```
+    pub fn from_datelike_with_holidays<T, P>(date: &T, provider: &P) -> Self
+    where
+        T: Datelike,
+        P: HolidayProvider<Holiday = H>,
+    {
+        let naive_date = to_naive_date(date);
+
         Self {
+            date: naive_date,
             day: date.day(),
+            day_sexagenary_cycle: SexagenaryCycle::of_day(date),
             era: JapaneseEra::from_datelike(date),
             heavenly_stem: HeavenlyStem::from_datelike(date),
-            holiday: JapaneseHoliday::holiday(date),
+            holiday: provider.holiday(&naive_date),
             month: JapaneseMonth::from_datelike(date),
             month_number: date.month(),
             sexagenary_cycle: SexagenaryCycle::from_datelike(date),
+            shuku: Shuku::from_datelike(date),
             weekday: JapaneseWeekday::from_datelike(date),
             western_year: date.year(),
             zodiac: JapaneseZodiac::from_datelike(date),
         }
     }
 
+    /// Returns the twelve day officer (`Choku`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{Choku, JapaneseDate};
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    ///
+    /// assert_eq!(Choku::Tatsu, japanese_date.choku());
+    /// ```
+    pub fn choku(&self) -> Choku {
+        Choku::from_datelike(&self.date)
+    }
+
+    /// Returns the name of twelve day officer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseDate;
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    ///
+    /// assert_eq!("除", japanese_date.choku_name());
+    /// ```
+    pub fn choku_name(&self) -> &'static str {
+        self.choku().name()
+    }
+
+    /// Returns the name of holiday provided by the holiday provider.
+    ///
+    /// For the default [`JapaneseHoliday`], [`JapaneseDate::holiday_name`] returns `&'static str` instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{HolidayCalendar, JapaneseDate};
+    ///
+    /// let calendar = HolidayCalendar::new().with_fixed_date("創立記念日", 6, 1);
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike_with_holidays(&chrono_date, &calendar);
+    ///
+    /// assert_eq!(Some("創立記念日"), japanese_date.custom_holiday_name());
+    /// ```
+    pub fn custom_holiday_name(&self) -> Option<&str> {
+        self.holiday.as_ref().map(|h| h.name())
+    }
+
     /// Returns the day.
     ///
     /// # Example
@@ -71,6 +194,40 @@ impl JapaneseDate {
         self.day
     }
 
+    /// Returns the `SexagenaryCycle` of the day.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{JapaneseDate, SexagenaryCycle};
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    ///
+    /// assert_eq!(SexagenaryCycle::KinoeNe, japanese_date.day_sexagenary_cycle());
+    /// ```
+    pub const fn day_sexagenary_cycle(&self) -> SexagenaryCycle {
+        self.day_sexagenary_cycle
+    }
+
+    /// Returns the name of sexagenary cycle of the day.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseDate;
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    ///
+    /// assert_eq!("乙丑", japanese_date.day_sexagenary_cycle_name());
+    /// ```
+    pub const fn day_sexagenary_cycle_name(&self) -> &'static str {
+        self.day_sexagenary_cycle.name()
+    }
+
     /// Returns the `JapaneseEra`.
     /// Note: Supported Reiwa, Heisei, Showa, Taisho and Meiji.
     ///
@@ -125,6 +282,26 @@ impl JapaneseDate {
         self.era.map(|e| e.name())
     }
 
+    /// Formats the date with the specified format string.
+    ///
+    /// See [`JapaneseDateFormat`] for the supported specifiers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseDate;
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 10, 17).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    ///
+    /// assert_eq!("令和6年10月17日(木)", japanese_date.format("%E%y年%m月%d日(%a)").to_string());
+    /// assert_eq!("令和六年十月十七日 木曜日", japanese_date.format("%E%KJ年%Km月%Kd日 %A").to_string());
+    /// ```
+    pub const fn format<'a>(&'a self, fmt: &'a str) -> JapaneseDateFormat<'a, H> {
+        JapaneseDateFormat::new(self, fmt)
+    }
+
     /// Returns the `HeavenlyStem`.
     ///
     /// # Example
@@ -159,7 +336,7 @@ impl JapaneseDate {
         self.heavenly_stem.name()
     }
 
-    /// Returns the `JapaneseHoliday`.
+    /// Returns the holiday, which is `JapaneseHoliday` by default.
     ///
     /// # Example
     ///
@@ -172,11 +349,28 @@ impl JapaneseDate {
     ///
     /// assert_eq!(JapaneseHoliday::NewYearsDay, japanese_date.holiday().unwrap());
     /// ```
-    pub const fn holiday(&self) -> Option<JapaneseHoliday> {
-        self.holiday
+    pub fn holiday(&self) -> Option<H> {
+        self.holiday.clone()
     }
 
-    /// Returns the name of Japanese holiday.
+    /// Returns the `Kou` which the date belongs to.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{JapaneseDate, Kou};
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 2, 4).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    ///
+    /// assert_eq!(Kou::Harukazekooriwotoku, japanese_date.kou());
+    /// ```
+    pub fn kou(&self) -> Kou {
+        Kou::from_datelike(&self.date)
+    }
+
+    /// Returns the name of kou which the date belongs to.
     ///
     /// # Example
     ///
@@ -184,13 +378,32 @@ impl JapaneseDate {
     /// use chrono::NaiveDate;
     /// use koyomi_rs::JapaneseDate;
     ///
-    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 10, 17).unwrap();
     /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
     ///
-    /// assert_eq!("元日", japanese_date.holiday_name().unwrap());
+    /// assert_eq!("菊花開", japanese_date.kou_name());
     /// ```
-    pub fn holiday_name(&self) -> Option<&'static str> {
-        self.holiday.map(|h| h.name())
+    pub fn kou_name(&self) -> &'static str {
+        self.kou().name()
+    }
+
+    /// Returns the date of lunisolar calendar (旧暦).
+    /// `None` is returned if the date is out of the supported period of [`LunisolarDate`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseDate;
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 9, 17).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    /// let lunisolar = japanese_date.lunisolar_date();
+    ///
+    /// assert_eq!(Some((8, 15)), lunisolar.map(|d| (d.month(), d.day())));
+    /// ```
+    pub fn lunisolar_date(&self) -> Option<LunisolarDate> {
+        LunisolarDate::from_datelike(&self.date)
     }
 
     /// Returns the `JapaneseMonth`.
@@ -244,6 +457,109 @@ impl JapaneseDate {
         self.month_number
     }
 
+    /// Returns the `SexagenaryCycle` of the month, which changes at 節入り.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{JapaneseDate, SexagenaryCycle};
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 2, 4).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    ///
+    /// assert_eq!(SexagenaryCycle::HinoeTora, japanese_date.month_sexagenary_cycle());
+    /// ```
+    pub fn month_sexagenary_cycle(&self) -> SexagenaryCycle {
+        SexagenaryCycle::of_month(&self.date)
+    }
+
+    /// Returns the name of sexagenary cycle of the month.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseDate;
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 10, 17).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    ///
+    /// assert_eq!("甲戌", japanese_date.month_sexagenary_cycle_name());
+    /// ```
+    pub fn month_sexagenary_cycle_name(&self) -> &'static str {
+        self.month_sexagenary_cycle().name()
+    }
+
+    /// Returns the `Rokuyo`.
+    /// `None` is returned if the date is out of the supported period of [`LunisolarDate`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{JapaneseDate, Rokuyo};
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 10, 17).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    ///
+    /// assert_eq!(Some(Rokuyo::Taian), japanese_date.rokuyo());
+    /// ```
+    pub fn rokuyo(&self) -> Option<Rokuyo> {
+        self.lunisolar_date().map(|d| Rokuyo::from_lunisolar(&d))
+    }
+
+    /// Returns the name of rokuyo.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseDate;
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 9, 17).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    ///
+    /// assert_eq!(Some("仏滅"), japanese_date.rokuyo_name());
+    /// ```
+    pub fn rokuyo_name(&self) -> Option<&'static str> {
+        self.rokuyo().map(|r| r.name())
+    }
+
+    /// Returns the auspicious and inauspicious days (`Senjitsu`) on the date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{JapaneseDate, Senjitsu};
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    ///
+    /// assert!(japanese_date.senjitsu().contains(&Senjitsu::Tenshanichi));
+    /// ```
+    pub fn senjitsu(&self) -> Vec<Senjitsu> {
+        Senjitsu::from_datelike_with_lunisolar(&self.date, self.lunisolar_date().as_ref())
+    }
+
+    /// Returns the names of auspicious and inauspicious days on the date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseDate;
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 1, 3).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    ///
+    /// assert_eq!(vec!["三隣亡", "寅の日"], japanese_date.senjitsu_names());
+    /// ```
+    pub fn senjitsu_names(&self) -> Vec<&'static str> {
+        self.senjitsu().iter().map(|s| s.name()).collect()
+    }
+
     /// Returns the `SexagenaryCycle`.
     ///
     /// # Example
@@ -278,6 +594,74 @@ impl JapaneseDate {
         self.sexagenary_cycle.name()
     }
 
+    /// Returns the lunar mansion (`Shuku`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{JapaneseDate, Shuku};
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 1, 11).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    ///
+    /// assert_eq!(Shuku::from_name("角"), Some(japanese_date.shuku()));
+    /// ```
+    pub const fn shuku(&self) -> Shuku {
+        self.shuku
+    }
+
+    /// Returns the name of lunar mansion.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseDate;
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    ///
+    /// assert_eq!("畢", japanese_date.shuku_name());
+    /// ```
+    pub const fn shuku_name(&self) -> &'static str {
+        self.shuku.name()
+    }
+
+    /// Returns the `SolarTerm` if it begins on the date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{JapaneseDate, SolarTerm};
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 2, 4).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    ///
+    /// assert_eq!(Some(SolarTerm::Risshun), japanese_date.solar_term());
+    /// ```
+    pub fn solar_term(&self) -> Option<SolarTerm> {
+        SolarTerm::from_datelike(&self.date)
+    }
+
+    /// Returns the name of solar term if it begins on the date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseDate;
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 12, 21).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    ///
+    /// assert_eq!(Some("冬至"), japanese_date.solar_term_name());
+    /// ```
+    pub fn solar_term_name(&self) -> Option<&'static str> {
+        self.solar_term().map(|t| t.name())
+    }
+
     /// Returns the `JapaneseWeekday`.
     ///
     /// # Example
@@ -329,6 +713,40 @@ impl JapaneseDate {
         self.western_year
     }
 
+    /// Returns the seasonal festival days (`Zassetsu`) on the date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{JapaneseDate, Zassetsu};
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 2, 3).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    ///
+    /// assert_eq!(vec![Zassetsu::Setsubun], japanese_date.zassetsu());
+    /// ```
+    pub fn zassetsu(&self) -> Vec<Zassetsu> {
+        Zassetsu::from_datelike(&self.date)
+    }
+
+    /// Returns the names of seasonal festival days on the date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::JapaneseDate;
+    ///
+    /// let chrono_date = NaiveDate::from_ymd_opt(2024, 7, 24).unwrap();
+    /// let japanese_date = JapaneseDate::from_datelike(&chrono_date);
+    ///
+    /// assert_eq!(vec!["夏の土用の丑の日"], japanese_date.zassetsu_names());
+    /// ```
+    pub fn zassetsu_names(&self) -> Vec<&'static str> {
+        self.zassetsu().iter().map(|z| z.name()).collect()
+    }
+
     /// Returns the `JapaneseZodiac`.
     ///
     /// # Example
@@ -367,9 +785,12 @@ impl JapaneseDate {
 /// Japanese calendar
 ///
 /// A calendar that includes Japanese-specific definitions ([`JapaneseDate`]).
+///
+/// The holidays are provided by a [`HolidayProvider`], Japanese national holidays by default.
 #[derive(Debug)]
-pub struct Koyomi {
+pub struct Koyomi<P = NationalHolidays> {
     current: NaiveDate,
+    provider: P,
     until: NaiveDate,
 }
 
@@ -391,6 +812,7 @@ impl Koyomi {
     /// ```
     pub fn between<T: Datelike>(from: &T, until: &T) -> Self {
         Self {
+            provider: NationalHolidays,
             current: NaiveDate::from_ymd_opt(from.year(), from.month(), from.day()).unwrap(),
             until: NaiveDate::from_ymd_opt(until.year(), until.month(), until.day()).unwrap(),
         }
@@ -449,6 +871,7 @@ impl Koyomi {
     /// ```
     pub fn from_now_until<T: Datelike>(until: &T) -> Self {
         Self {
+            provider: NationalHolidays,
             current: Local::now().date_naive(),
             until: NaiveDate::from_ymd_opt(until.year(), until.month(), until.day()).unwrap(),
         }
@@ -469,6 +892,7 @@ impl Koyomi {
             .map(|d| (d, d + Months::new(1)))
             .map(|(c, n)| (c, n.pred_opt().unwrap()))
             .map(|(c, n)| Self {
+                provider: NationalHolidays,
                 current: c,
                 until: n,
             })
@@ -491,6 +915,7 @@ impl Koyomi {
     /// ```
     pub fn until_now_from<T: Datelike>(from: &T) -> Self {
         Self {
+            provider: NationalHolidays,
             current: NaiveDate::from_ymd_opt(from.year(), from.month(), from.day()).unwrap(),
             until: Local::now().date_naive(),
         }
@@ -508,14 +933,74 @@ impl Koyomi {
     /// ```
     pub fn year_of(year: i32) -> Self {
         Self {
+            provider: NationalHolidays,
             current: NaiveDate::from_ymd_opt(year, 1, 1).unwrap(),
             until: NaiveDate::from_ymd_opt(year, 12, 31).unwrap(),
         }
     }
 }
 
-impl Iterator for Koyomi {
-    type Item = JapaneseDate;
+impl<P: HolidayProvider> Koyomi<P> {
+    /// Returns the dates of the calendar which are the specified `Senjitsu`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::{Koyomi, Senjitsu};
+    ///
+    /// let days: Vec<_> = Koyomi::year_of(2024)
+    ///     .senjitsu_days(Senjitsu::Tenshanichi)
+    ///     .map(|d| (d.month_number(), d.day()))
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     vec![(1, 1), (3, 15), (5, 30), (7, 29), (8, 12), (10, 11), (12, 26)],
+    ///     days
+    /// );
+    /// ```
+    pub fn senjitsu_days(
+        self,
+        senjitsu: Senjitsu,
+    ) -> impl Iterator<Item = JapaneseDate<P::Holiday>> {
+        // The months are calculated only once for each lunisolar year.
+        let mut lunisolar_year: Option<LunisolarYear> = None;
+
+        self.filter(move |d| {
+            let cached = lunisolar_year.as_ref().and_then(|y| y.date_of(&d.date));
+            let lunisolar_date = cached.or_else(|| {
+                lunisolar_year = LunisolarYear::of(&d.date);
+                lunisolar_year.as_ref()?.date_of(&d.date)
+            });
+
+            Senjitsu::from_datelike_with_lunisolar(&d.date, lunisolar_date.as_ref())
+                .contains(&senjitsu)
+        })
+    }
+
+    /// Apply the holidays of the provider to the calendar.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::{HolidayCalendar, Koyomi};
+    ///
+    /// let calendar = HolidayCalendar::new().with_fixed_date("創立記念日", 7, 1);
+    /// let koyomi = Koyomi::month_of(2024, 7).unwrap().with_holidays(calendar);
+    ///
+    /// // 創立記念日 and 海の日
+    /// assert_eq!(2, koyomi.filter(|d| d.is_holiday()).count());
+    /// ```
+    pub fn with_holidays<Q: HolidayProvider>(self, provider: Q) -> Koyomi<Q> {
+        Koyomi {
+            current: self.current,
+            provider,
+            until: self.until,
+        }
+    }
+}
+
+impl<P: HolidayProvider> Iterator for Koyomi<P> {
+    type Item = JapaneseDate<P::Holiday>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.until < self.current {
@@ -523,7 +1008,11 @@ impl Iterator for Koyomi {
         } else {
             let current = self.current;
             self.current = current.succ_opt()?;
-            Some(JapaneseDate::from_datelike(&current))
+
+            Some(JapaneseDate::from_datelike_with_holidays(
+                &current,
+                &self.provider,
+            ))
         }
     }
 }
@@ -531,6 +1020,7 @@ impl Iterator for Koyomi {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::custom::HolidayCalendar;
     use chrono::{Days, Local, NaiveDate};
     use rstest::rstest;
 
@@ -540,7 +1030,7 @@ mod tests {
         let until = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
 
         let mut koyomi = Koyomi::between(&from, &until);
-        let first = koyomi.nth(0).unwrap();
+        let first = koyomi.next().unwrap();
         let last = koyomi.last().unwrap();
 
         assert_eq!(
@@ -553,13 +1043,90 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn 一年のカレンダーに二十四節気が含まれる() {
+        let terms: Vec<&str> = Koyomi::year_of(2024)
+            .filter_map(|date| date.solar_term_name())
+            .collect();
+
+        assert_eq!(24, terms.len());
+        assert_eq!(Some(&"小寒"), terms.first());
+        assert_eq!(Some(&"冬至"), terms.last());
+    }
+
+    #[rstest]
+    fn カレンダーの各日付に六曜が含まれる() {
+        let rokuyo: Vec<&str> = Koyomi::month_of(2024, 2)
+            .unwrap()
+            .skip(9)
+            .take(7)
+            .map(|date| date.rokuyo_name().unwrap())
+            .collect();
+
+        assert_eq!(
+            vec!["先勝", "友引", "先負", "仏滅", "大安", "赤口", "先勝"],
+            rokuyo
+        );
+    }
+
+    #[rstest]
+    fn カレンダーの旧暦は各日付から変換したものと一致する() {
+        let from = NaiveDate::from_ymd_opt(2022, 12, 1).unwrap();
+        let until = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+
+        for date in Koyomi::between(&from, &until) {
+            let naive_date =
+                NaiveDate::from_ymd_opt(date.western_year(), date.month_number(), date.day())
+                    .unwrap();
+
+            assert_eq!(
+                LunisolarDate::from_datelike(&naive_date),
+                date.lunisolar_date()
+            );
+        }
+    }
+
+    #[rstest]
+    #[case(NaiveDate::MIN)]
+    #[case(NaiveDate::MAX)]
+    fn 旧暦の範囲外の日付では旧暦と六曜を導出しない(#[case] date: NaiveDate) {
+        let japanese_date = JapaneseDate::from_datelike(&date);
+
+        assert!(japanese_date.lunisolar_date().is_none());
+        assert!(japanese_date.rokuyo().is_none());
+    }
+
+    #[rstest]
+    fn カレンダーの各日付に十二直と二十八宿が含まれる() {
+        let days: Vec<(&str, &str)> = Koyomi::month_of(2024, 2)
+            .unwrap()
+            .skip(2)
+            .take(3)
+            .map(|date| (date.choku_name(), date.shuku_name()))
+            .collect();
+
+        // 十二直 is repeated on 立春 (2024-02-04).
+        assert_eq!(vec![("成", "柳"), ("成", "星"), ("納", "張")], days);
+    }
+
+    #[rstest]
+    fn カレンダーから選日を検索できる() {
+        let days: Vec<u32> = Koyomi::month_of(2024, 1)
+            .unwrap()
+            .senjitsu_days(Senjitsu::Ichiryumanbaibi)
+            .map(|date| date.day())
+            .collect();
+
+        assert_eq!(vec![1, 13, 16, 25, 28], days);
+    }
+
     #[rstest]
     fn 現在から指定した年月日までのカレンダーを生成できる() {
         let now = Local::now().date_naive();
         let until = now.checked_add_days(Days::new(10)).unwrap();
 
         let mut koyomi = Koyomi::from_now_until(&until);
-        let first = koyomi.nth(0).unwrap();
+        let first = koyomi.next().unwrap();
         let last = koyomi.last().unwrap();
 
         assert_eq!(
@@ -578,7 +1145,7 @@ mod tests {
         let from = now.checked_sub_days(Days::new(10)).unwrap();
 
         let mut koyomi = Koyomi::until_now_from(&from);
-        let first = koyomi.nth(0).unwrap();
+        let first = koyomi.next().unwrap();
         let last = koyomi.last().unwrap();
 
         assert_eq!(
@@ -602,7 +1169,7 @@ mod tests {
     #[rstest]
     fn 指定した年月のカレンダーを生成できる() {
         let mut koyomi = Koyomi::month_of(2025, 2).unwrap();
-        let first = koyomi.nth(0).unwrap();
+        let first = koyomi.next().unwrap();
         let last = koyomi.last().unwrap();
 
         assert_eq!(
@@ -614,4 +1181,73 @@ mod tests {
             (last.western_year(), last.month_number(), last.day())
         );
     }
+
+    #[rstest]
+    fn 独自の休日を含むカレンダーを生成できる() {
+        let calendar = HolidayCalendar::new()
+            .with_fixed_date("年末年始休暇", 12, 31)
+            .with_fixed_date("年末年始休暇", 1, 1)
+            .with_fixed_date("年末年始休暇", 1, 2);
+        let from = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
+        let until = NaiveDate::from_ymd_opt(2025, 1, 3).unwrap();
+
+        let holidays: Vec<_> = Koyomi::between(&from, &until)
+            .with_holidays(calendar)
+            .map(|d| d.custom_holiday_name().map(|n| n.to_string()))
+            .collect();
+
+        assert_eq!(
+            vec![
+                None,
+                Some("年末年始休暇".to_string()),
+                Some("元日".to_string()),
+                Some("年末年始休暇".to_string()),
+                None,
+            ],
+            holidays
+        );
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    struct FakeHoliday;
+
+    impl Holiday for FakeHoliday {
+        fn name(&self) -> &str {
+            "テスト休日"
+        }
+    }
+
+    #[derive(Debug)]
+    struct EveryMonday;
+
+    impl HolidayProvider for EveryMonday {
+        type Holiday = FakeHoliday;
+
+        fn holiday(&self, date: &NaiveDate) -> Option<Self::Holiday> {
+            (date.weekday() == chrono::Weekday::Mon).then_some(FakeHoliday)
+        }
+    }
+
+    #[rstest]
+    fn 休日の提供元を差し替えたカレンダーを生成できる() {
+        let koyomi = Koyomi::month_of(2024, 1)
+            .unwrap()
+            .with_holidays(EveryMonday);
+        let holidays: Vec<_> = koyomi
+            .filter(|d| d.is_holiday())
+            .map(|d| (d.day(), d.custom_holiday_name().unwrap().to_string()))
+            .collect();
+
+        // 元日 is not a holiday of the provider.
+        assert_eq!(
+            vec![
+                (1, "テスト休日".to_string()),
+                (8, "テスト休日".to_string()),
+                (15, "テスト休日".to_string()),
+                (22, "テスト休日".to_string()),
+                (29, "テスト休日".to_string()),
+            ],
+            holidays
+        );
+    }
 }
@@ -12,9 +12,20 @@
 //! In Japan, in addition to Gregorian calendar, the Japanese era system is also used.
 //!
 //! Using [`JapaneseEra`], it is possible to derive the Japanese era from the Gregorian calendar.
+//! Conversely, a date written in Japanese era such as `令和6年5月1日` can be parsed
+//! with [`JapaneseEra::parse_date`].
 //!
-//! Please note that it is not possible to derive Japanese eras before the Meiji era.
-//! Prior to the Meiji era, the derivation is based on the lunar calendar, which is not currentry supported.
+//! [`JapaneseEra`] supports the eras after the Meiji era.
+//! For the eras prior to the Meiji era (from 大化 to 慶応), use [`HistoricalEra`] instead.
+//!
+//! The zodiac and the sexagenary cycle of a year change on January 1 by default.
+//! With [`YearBoundary::Risshun`], they change on 立春 as in fortune-telling.
+//!
+//! The nine stars (九星) of the year, the month and the day are derived by [`NineStar`].
+//!
+//! The heavenly stems and the Japanese zodiac also provide their readings,
+//! [`FiveElement`] and [`YinYang`] for fortune-telling.
+//! Each sexagenary cycle also belongs to one of the thirty [`Nacchin`] (納音).
 //!
 //! ### Month
 //!
@@ -23,6 +34,20 @@
 //!
 //! By using [`JapaneseMonth`], it is possible to derive these.
 //!
+//! These names originally belong to the lunisolar calendar (旧暦).
+//! [`LunisolarDate`] converts dates between Gregorian calendar and the lunisolar calendar.
+//!
+//! ### Rokuyo
+//!
+//! 六曜 such as 大安 and 仏滅 are still popular for choosing dates of weddings and funerals.
+//!
+//! By using [`Rokuyo`], it is possible to derive them from the lunisolar calendar.
+//!
+//! Other auspicious and inauspicious days (選日) such as 一粒万倍日 and 天赦日
+//! are derived by [`Senjitsu`].
+//! The twelve day officers (十二直) and the twenty-eight lunar mansions (二十八宿)
+//! are derived by [`Choku`] and [`Shuku`].
+//!
 //! ### Weekday
 //!
 //! Similarly to months, there are unique names for weekday in Japanese.
@@ -36,33 +61,125 @@
 //!
 //! By using [`JapaneseHoliday`], it is possible to derive these.
 //!
+//! Custom holidays such as company holidays can be layered on them with [`HolidayCalendar`].
+//! Any other source of holidays can be used by implementing [`HolidayProvider`].
+//!
+//! Based on the holidays, [`BusinessCalendar`] supports business day arithmetic
+//! such as the next business day.
+//!
+//! ### Solar terms
+//!
+//! The traditional Japanese almanac divides a year into 24 solar terms (二十四節気)
+//! such as 立春 and 冬至, based on the apparent solar longitude.
+//!
+//! By using [`SolarTerm`], it is possible to derive them with the exact time in JST.
+//! Each solar term is further divided into three pentads (七十二候) such as 東風解凍,
+//! which are derived by [`Kou`].
+//!
+//! Seasonal festival days (雑節) such as 節分, 彼岸 and 土用の丑の日 are derived by [`Zassetsu`].
+//!
 //! ### Calendar
 //!
 //! It supports generating calendars using each of the above,
 //! including both common Gregorian dates and Japanese-specific expression.
 //!
 //! By using [`Koyomi`], you can generate calendars.
+//!
+//! Each [`JapaneseDate`] can be formatted in Japanese style such as `令和6年10月17日(木)`
+//! with [`JapaneseDate::format`].
+//! Kanji numerals and full-width digits are handled by the [`numerals`] module.
+//!
+//! ### Age
+//!
+//! 満年齢 including the legal age under 年齢計算ニ関スル法律 and 数え年
+//! are calculated by the [`age`] module.
+pub mod age;
+
+mod astronomy;
+
+mod business;
+pub use business::BusinessCalendar;
+
+mod choku;
+pub use choku::Choku;
+
+mod custom;
+pub use custom::{CalendarHoliday, CustomHoliday, CustomHolidayRule, HolidayCalendar};
+
 mod day;
-pub use day::{JapaneseHoliday, JapaneseWeekday};
+pub use day::{JapaneseHoliday, JapaneseWeekday, UpcomingHolidays};
 
 mod era;
-pub use era::JapaneseEra;
+pub use era::{JapaneseEra, ParseEraDateError, ProclaimedEra, RegisterEraError};
+
+mod format;
+pub use format::JapaneseDateFormat;
+
+mod historical;
+pub use historical::{Court, HistoricalEra};
 
 mod internal;
 
 mod koyomi;
 pub use koyomi::{JapaneseDate, Koyomi};
 
+mod kou;
+pub use kou::Kou;
+
+mod lunisolar;
+pub use lunisolar::LunisolarDate;
+
 mod month;
 pub use month::JapaneseMonth;
 
+mod nacchin;
+pub use nacchin::Nacchin;
+
+pub mod numerals;
+
+mod provider;
+pub use provider::{Holiday, HolidayProvider, NationalHolidays};
+
+mod rokuyo;
+pub use rokuyo::Rokuyo;
+
+mod senjitsu;
+pub use senjitsu::Senjitsu;
+
+mod shuku;
+pub use shuku::Shuku;
+
+mod solar_term;
+pub use solar_term::SolarTerm;
+
 mod year;
-pub use year::{HeavenlyStem, JapaneseZodiac, SexagenaryCycle};
+pub use year::{
+    FiveElement, HeavenlyStem, JapaneseZodiac, NineStar, SexagenaryCycle, YearBoundary, YinYang,
+};
+
+mod zassetsu;
+pub use zassetsu::Zassetsu;
 
 pub mod prelude {
-    pub use crate::day::{JapaneseHoliday, JapaneseWeekday};
-    pub use crate::era::JapaneseEra;
+    pub use crate::business::BusinessCalendar;
+    pub use crate::choku::Choku;
+    pub use crate::custom::{CalendarHoliday, CustomHoliday, CustomHolidayRule, HolidayCalendar};
+    pub use crate::day::{JapaneseHoliday, JapaneseWeekday, UpcomingHolidays};
+    pub use crate::era::{JapaneseEra, ParseEraDateError, ProclaimedEra, RegisterEraError};
+    pub use crate::format::JapaneseDateFormat;
+    pub use crate::historical::{Court, HistoricalEra};
+    pub use crate::kou::Kou;
     pub use crate::koyomi::{JapaneseDate, Koyomi};
+    pub use crate::lunisolar::LunisolarDate;
     pub use crate::month::JapaneseMonth;
-    pub use crate::year::{HeavenlyStem, JapaneseZodiac, SexagenaryCycle};
+    pub use crate::nacchin::Nacchin;
+    pub use crate::provider::{Holiday, HolidayProvider, NationalHolidays};
+    pub use crate::rokuyo::Rokuyo;
+    pub use crate::senjitsu::Senjitsu;
+    pub use crate::shuku::Shuku;
+    pub use crate::solar_term::SolarTerm;
+    pub use crate::year::{
+        FiveElement, HeavenlyStem, JapaneseZodiac, NineStar, SexagenaryCycle, YearBoundary, YinYang,
+    };
+    pub use crate::zassetsu::Zassetsu;
 }
@@ -0,0 +1,442 @@
+use std::ops::RangeInclusive;
+
+use crate::astronomy::{
+    TROPICAL_YEAR, days_from_ce_in_jst, jde_from_date, new_moons_between, solar_longitude,
+    solar_longitude_jde, solar_longitude_jde_near,
+};
+use crate::internal::to_naive_date;
+use crate::month::JapaneseMonth;
+use chrono::{Datelike, NaiveDate};
+
+/// Lunisolar date (旧暦)
+///
+/// It converts dates between Gregorian calendar and the lunisolar calendar
+/// in the manner of 天保暦, the last lunisolar calendar officially used in Japan.
+///
+/// - Each month begins on the date of the new moon in JST.
+/// - The month containing 冬至 is the 11th month, and the other months are numbered
+///   in order from it.
+/// - If there are 13 months until the next 11th month, the first month without
+///   a principal term (中気) becomes a leap month (閏月) of the previous month.
+///
+/// It is calculated astronomically, so that dates before 1844
+/// (when 天保暦 was adopted) may differ from the historical calendars.
+/// The supported period is from the year 1 to 9999 of Gregorian calendar,
+/// outside of which the astronomical calculation is no longer accurate.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct LunisolarDate {
+    year: i32,
+    month: u32,
+    is_leap_month: bool,
+    day: u32,
+}
+
+impl LunisolarDate {
+    /// Returns the day of lunisolar month.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::LunisolarDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 10, 17).unwrap();
+    /// assert_eq!(15, LunisolarDate::from_datelike(&date).unwrap().day());
+    /// ```
+    pub const fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Generate from Datelike of chrono.
+    ///
+    /// Returns `None` if the date is out of the supported period (from the year 1 to 9999).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::LunisolarDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+    /// let lunisolar = LunisolarDate::from_datelike(&date).unwrap();
+    ///
+    /// assert_eq!((2024, 1, false, 1), (
+    ///     lunisolar.year(),
+    ///     lunisolar.month(),
+    ///     lunisolar.is_leap_month(),
+    ///     lunisolar.day()
+    /// ));
+    ///
+    /// assert!(LunisolarDate::from_datelike(&NaiveDate::MAX).is_none());
+    /// ```
+    pub fn from_datelike<T: Datelike>(date: &T) -> Option<Self> {
+        LunisolarYear::of(date)?.date_of(date)
+    }
+
+    /// Returns whether the month is a leap month (閏月).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::LunisolarDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2023, 3, 22).unwrap(); // 閏2月1日
+    /// assert!(LunisolarDate::from_datelike(&date).unwrap().is_leap_month());
+    /// ```
+    pub const fn is_leap_month(&self) -> bool {
+        self.is_leap_month
+    }
+
+    /// Returns the Japanese name of lunisolar month such as 睦月.
+    ///
+    /// A leap month has the same name as the previous month.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{JapaneseMonth, LunisolarDate};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+    /// assert_eq!(JapaneseMonth::Mutsuki, LunisolarDate::from_datelike(&date).unwrap().japanese_month());
+    /// ```
+    pub const fn japanese_month(&self) -> JapaneseMonth {
+        match JapaneseMonth::from_number(self.month as usize) {
+            Some(month) => month,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns the number of lunisolar month.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::LunisolarDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 10, 17).unwrap();
+    /// assert_eq!(9, LunisolarDate::from_datelike(&date).unwrap().month());
+    /// ```
+    pub const fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the Japanese name of lunisolar month.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::LunisolarDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 10, 17).unwrap();
+    /// assert_eq!("長月", LunisolarDate::from_datelike(&date).unwrap().month_name());
+    /// ```
+    pub const fn month_name(&self) -> &'static str {
+        self.japanese_month().name()
+    }
+
+    /// Generate from year, month, leap month flag and day of lunisolar calendar.
+    ///
+    /// Returns `None` if the date doesn't exist in the lunisolar calendar
+    /// or is out of the supported period.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::LunisolarDate;
+    ///
+    /// let lunisolar = LunisolarDate::new(2023, 2, true, 1).unwrap();
+    ///
+    /// assert_eq!(Some(NaiveDate::from_ymd_opt(2023, 3, 22).unwrap()), lunisolar.to_naive_date());
+    /// assert!(LunisolarDate::new(2024, 2, true, 1).is_none());
+    /// ```
+    pub fn new(year: i32, month: u32, is_leap_month: bool, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) || !(1..=30).contains(&day) {
+            return None;
+        }
+
+        let lunisolar = LunisolarDate {
+            year,
+            month,
+            is_leap_month,
+            day,
+        };
+        lunisolar.to_naive_date().map(|_| lunisolar)
+    }
+
+    /// Convert into NaiveDate of chrono.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::LunisolarDate;
+    ///
+    /// let lunisolar = LunisolarDate::new(2024, 8, false, 15).unwrap(); // 十五夜
+    /// assert_eq!(Some(NaiveDate::from_ymd_opt(2024, 9, 17).unwrap()), lunisolar.to_naive_date());
+    /// ```
+    pub fn to_naive_date(&self) -> Option<NaiveDate> {
+        let solstice_year = if self.month >= 11 {
+            self.year
+        } else {
+            self.year - 1
+        };
+        let months = months_from_solstice(solar_longitude_jde(solstice_year, 270.0)?)?;
+
+        let index = months[..months.len() - 1]
+            .iter()
+            .position(|m| m.number == self.month && m.is_leap == self.is_leap_month)?;
+        let start = months[index].start;
+        if months[index + 1].start - start < self.day as i64 {
+            return None;
+        }
+
+        i32::try_from(start + self.day as i64 - 1)
+            .ok()
+            .and_then(NaiveDate::from_num_days_from_ce_opt)
+            .filter(|d| SUPPORTED_YEARS.contains(&d.year()))
+    }
+
+    /// Returns the year of lunisolar calendar.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::LunisolarDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(); // 11月20日 in 2023
+    /// assert_eq!(2023, LunisolarDate::from_datelike(&date).unwrap().year());
+    /// ```
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+}
+
+/// The Gregorian years in which the lunisolar calendar is calculated.
+const SUPPORTED_YEARS: RangeInclusive<i32> = 1..=9999;
+
+/// The months of a lunisolar year from the 11th month containing the winter solstice.
+///
+/// It is kept by [`Koyomi`](crate::Koyomi) to avoid calculating the same months for every day.
+#[derive(Debug)]
+pub(crate) struct LunisolarYear {
+    months: Vec<LunarMonth>,
+}
+
+impl LunisolarYear {
+    /// Returns the lunisolar year which contains the date.
+    pub(crate) fn of<T: Datelike>(date: &T) -> Option<Self> {
+        if !SUPPORTED_YEARS.contains(&date.year()) {
+            return None;
+        }
+
+        let days = date.num_days_from_ce() as i64;
+        let jde = jde_from_date(&to_naive_date(date)) + 1.0;
+
+        // The winter solstice before the end of the date.
+        let passed = (solar_longitude(jde) - 270.0).rem_euclid(360.0);
+        let mut solstice = solar_longitude_jde_near(jde - passed / 360.0 * TROPICAL_YEAR, 270.0);
+        let mut months = months_from_solstice(solstice)?;
+        if days < months[0].start {
+            solstice = solar_longitude_jde_near(solstice - TROPICAL_YEAR, 270.0);
+            months = months_from_solstice(solstice)?;
+        } else if months[months.len() - 1].start <= days {
+            solstice = solar_longitude_jde_near(solstice + TROPICAL_YEAR, 270.0);
+            months = months_from_solstice(solstice)?;
+        }
+
+        Some(LunisolarYear { months })
+    }
+
+    /// Returns the lunisolar date, or `None` unless the year contains the date.
+    pub(crate) fn date_of<T: Datelike>(&self, date: &T) -> Option<LunisolarDate> {
+        let days = date.num_days_from_ce() as i64;
+        if !SUPPORTED_YEARS.contains(&date.year())
+            || self.months[self.months.len() - 1].start <= days
+        {
+            return None;
+        }
+
+        let month = self.months.iter().rfind(|m| m.start <= days)?;
+        // The 11th and 12th months may continue until February of the next Gregorian year.
+        let year = match (month.number, date.month()) {
+            (11.., 1 | 2) => date.year() - 1,
+            _ => date.year(),
+        };
+
+        Some(LunisolarDate {
+            year,
+            month: month.number,
+            is_leap_month: month.is_leap,
+            day: (days - month.start) as u32 + 1,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct LunarMonth {
+    number: u32,
+    is_leap: bool,
+    start: i64,
+}
+
+/// Returns the months from the 11th month containing the winter solstice
+/// until the 11th month containing the next winter solstice.
+fn months_from_solstice(solstice: f64) -> Option<Vec<LunarMonth>> {
+    let next = solar_longitude_jde_near(solstice + TROPICAL_YEAR, 270.0);
+    let (first, last) = (days_from_ce_in_jst(solstice), days_from_ce_in_jst(next));
+
+    let starts: Vec<i64> = new_moons_between(solstice - 31.0, next + 1.0)
+        .into_iter()
+        .map(days_from_ce_in_jst)
+        .collect();
+    let i0 = starts.iter().rposition(|&d| d <= first)?;
+    let i1 = starts.iter().rposition(|&d| d <= last)?;
+    let starts = &starts[i0..=i1];
+
+    // The principal terms (中気) are every 30 degrees from the winter solstice.
+    // They are needed only to find the leap month.
+    let mut needs_leap = starts.len() == 14;
+    let principal_terms: Vec<i64> = (0..12)
+        .take_while(|_| needs_leap)
+        .map(|i| {
+            let jde = solstice + i as f64 * TROPICAL_YEAR / 12.0;
+            let longitude = (270.0 + 30.0 * i as f64) % 360.0;
+            days_from_ce_in_jst(solar_longitude_jde_near(jde, longitude))
+        })
+        .collect();
+
+    let mut number = 11;
+    let mut months = Vec::with_capacity(starts.len());
+    for (i, window) in starts.windows(2).enumerate() {
+        let has_principal_term = principal_terms
+            .iter()
+            .any(|&d| window[0] <= d && d < window[1]);
+        if i > 0 && needs_leap && !has_principal_term {
+            needs_leap = false;
+            months.push(LunarMonth {
+                number,
+                is_leap: true,
+                start: window[0],
+            });
+            continue;
+        }
+        if i > 0 {
+            number = number % 12 + 1;
+        }
+        months.push(LunarMonth {
+            number,
+            is_leap: false,
+            start: window[0],
+        });
+    }
+    months.push(LunarMonth {
+        number: 11,
+        is_leap: false,
+        start: starts[starts.len() - 1],
+    });
+
+    Some(months)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::Days;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case((2024, 2, 10), (2024, 1, false, 1))]
+    #[case((2024, 1, 1), (2023, 11, false, 20))]
+    #[case((2024, 9, 17), (2024, 8, false, 15))]
+    #[case((2024, 10, 17), (2024, 9, false, 15))]
+    #[case((2023, 3, 22), (2023, 2, true, 1))]
+    #[case((2023, 4, 20), (2023, 3, false, 1))]
+    #[case((2025, 7, 25), (2025, 6, true, 1))]
+    #[case((2025, 8, 23), (2025, 7, false, 1))]
+    #[case((2020, 5, 23), (2020, 4, true, 1))]
+    #[case((2017, 6, 24), (2017, 5, true, 1))]
+    #[case((2014, 10, 24), (2014, 9, true, 1))]
+    #[case((2033, 11, 22), (2033, 11, false, 1))]
+    #[case((2033, 12, 22), (2033, 11, true, 1))]
+    #[case((2034, 1, 20), (2033, 12, false, 1))]
+    #[case((2000, 1, 1), (1999, 11, false, 25))]
+    #[case((1900, 1, 31), (1900, 1, false, 1))]
+    fn 年月日から変換できる(
+        #[case] date: (i32, u32, u32),
+        #[case] expect: (i32, u32, bool, u32),
+    ) {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+        let lunisolar = LunisolarDate::from_datelike(&date).unwrap();
+
+        assert_eq!(
+            expect,
+            (
+                lunisolar.year(),
+                lunisolar.month(),
+                lunisolar.is_leap_month(),
+                lunisolar.day()
+            )
+        );
+        assert_eq!(Some(date), lunisolar.to_naive_date());
+    }
+
+    #[rstest]
+    fn 旧暦と新暦を相互に変換できる() {
+        let mut date = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        let until = NaiveDate::from_ymd_opt(2035, 12, 31).unwrap();
+
+        while date <= until {
+            let lunisolar = LunisolarDate::from_datelike(&date).unwrap();
+            let (y, m, leap, d) = (
+                lunisolar.year(),
+                lunisolar.month(),
+                lunisolar.is_leap_month(),
+                lunisolar.day(),
+            );
+
+            assert_eq!(Some(lunisolar), LunisolarDate::new(y, m, leap, d));
+            assert_eq!(Some(date), lunisolar.to_naive_date());
+            date = date.checked_add_days(Days::new(5)).unwrap();
+        }
+    }
+
+    #[rstest]
+    #[case(NaiveDate::MIN)]
+    #[case(NaiveDate::from_ymd_opt(0, 12, 31).unwrap())]
+    #[case(NaiveDate::from_ymd_opt(10000, 1, 1).unwrap())]
+    #[case(NaiveDate::MAX)]
+    fn 範囲外の日付は変換できない(#[case] date: NaiveDate) {
+        assert!(LunisolarDate::from_datelike(&date).is_none());
+    }
+
+    #[rstest]
+    #[case(2024, 2, true, 1)]
+    #[case(2024, 0, false, 1)]
+    #[case(2024, 13, false, 1)]
+    #[case(2024, 1, false, 0)]
+    #[case(2024, 1, false, 31)]
+    #[case(2024, 1, false, 30)]
+    fn 存在しない旧暦の日付は生成できない(
+        #[case] year: i32,
+        #[case] month: u32,
+        #[case] is_leap_month: bool,
+        #[case] day: u32,
+    ) {
+        assert!(LunisolarDate::new(year, month, is_leap_month, day).is_none());
+    }
+
+    #[rstest]
+    fn 旧暦の月名を導出できる() {
+        let lunisolar = LunisolarDate::new(2023, 2, true, 1).unwrap();
+
+        assert_eq!(JapaneseMonth::Kisaragi, lunisolar.japanese_month());
+        assert_eq!("如月", lunisolar.month_name());
+    }
+}
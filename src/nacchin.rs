@@ -0,0 +1,291 @@
+use crate::internal::NACCHIN;
+use crate::year::{FiveElement, SexagenaryCycle};
+
+use self::Nacchin::*;
+
+/// Nacchin (納音)
+///
+/// Each pair of the sexagenary cycle, such as 甲子 and 乙丑, shares one of the thirty 納音.
+///
+/// You can convert between the order of 納音,
+/// noting that it starts from `1` (海中金).
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Nacchin {
+    /// 海中金
+    Kaichuukin,
+    /// 炉中火
+    Rochuuka,
+    /// 大林木
+    Dairinboku,
+    /// 路傍土
+    Roboudo,
+    /// 剣鋒金
+    Jinpoukin,
+    /// 山頭火
+    Santouka,
+    /// 澗下水
+    Kankasui,
+    /// 城頭土
+    Joutoudo,
+    /// 白鑞金
+    Hakuroukin,
+    /// 楊柳木
+    Youryuuboku,
+    /// 井泉水
+    Seisensui,
+    /// 屋上土
+    Okujoudo,
+    /// 霹靂火
+    Hekirekika,
+    /// 松柏木
+    Shouhakuboku,
+    /// 長流水
+    Chouryuusui,
+    /// 沙中金
+    Sachuukin,
+    /// 山下火
+    Sangeka,
+    /// 平地木
+    Heichiboku,
+    /// 壁上土
+    Hekijoudo,
+    /// 金箔金
+    Kinpakukin,
+    /// 覆燈火
+    Fukutouka,
+    /// 天河水
+    Tengasui,
+    /// 大駅土
+    Taiekido,
+    /// 釵釧金
+    Sasenkin,
+    /// 桑柘木
+    Soushakuboku,
+    /// 大渓水
+    Daikeisui,
+    /// 沙中土
+    Sachuudo,
+    /// 天上火
+    Tenjouka,
+    /// 柘榴木
+    Zakuroboku,
+    /// 大海水
+    Taikaisui,
+}
+
+impl Nacchin {
+    /// Returns the `FiveElement` of 納音, which is the last character of the name.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::{FiveElement, Nacchin};
+    ///
+    /// assert_eq!(FiveElement::Fire, Nacchin::Rochuuka.element());
+    /// ```
+    pub const fn element(&self) -> FiveElement {
+        use FiveElement::*;
+
+        // The elements repeat every 15 of 納音.
+        const ELEMENTS: [FiveElement; 15] = [
+            Metal, Fire, Wood, Earth, Metal, Fire, Water, Earth, Metal, Wood, Water, Earth, Fire,
+            Wood, Water,
+        ];
+        ELEMENTS[(self.to_number() - 1) % 15]
+    }
+
+    /// Generate from name of 納音.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Nacchin;
+    ///
+    /// assert_eq!(Some(Nacchin::Taikaisui), Nacchin::from_name("大海水"));
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        NACCHIN
+            .iter()
+            .position(|&(x, _)| x == name)
+            .and_then(|i| Nacchin::from_number(i + 1))
+    }
+
+    /// Generate from number of 納音.
+    /// The index starts from `1` (海中金).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Nacchin;
+    ///
+    /// assert_eq!(Some(Nacchin::Taikaisui), Nacchin::from_number(30));
+    /// assert!(Nacchin::from_number(31).is_none());
+    /// ```
+    pub const fn from_number(num: usize) -> Option<Self> {
+        match num {
+            1 => Some(Kaichuukin),
+            2 => Some(Rochuuka),
+            3 => Some(Dairinboku),
+            4 => Some(Roboudo),
+            5 => Some(Jinpoukin),
+            6 => Some(Santouka),
+            7 => Some(Kankasui),
+            8 => Some(Joutoudo),
+            9 => Some(Hakuroukin),
+            10 => Some(Youryuuboku),
+            11 => Some(Seisensui),
+            12 => Some(Okujoudo),
+            13 => Some(Hekirekika),
+            14 => Some(Shouhakuboku),
+            15 => Some(Chouryuusui),
+            16 => Some(Sachuukin),
+            17 => Some(Sangeka),
+            18 => Some(Heichiboku),
+            19 => Some(Hekijoudo),
+            20 => Some(Kinpakukin),
+            21 => Some(Fukutouka),
+            22 => Some(Tengasui),
+            23 => Some(Taiekido),
+            24 => Some(Sasenkin),
+            25 => Some(Soushakuboku),
+            26 => Some(Daikeisui),
+            27 => Some(Sachuudo),
+            28 => Some(Tenjouka),
+            29 => Some(Zakuroboku),
+            30 => Some(Taikaisui),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of 納音.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Nacchin;
+    ///
+    /// assert_eq!("海中金", Nacchin::Kaichuukin.name());
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        NACCHIN[self.to_number() - 1].0
+    }
+
+    /// Returns the reading of 納音 in hiragana.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Nacchin;
+    ///
+    /// assert_eq!("かいちゅうきん", Nacchin::Kaichuukin.reading());
+    /// ```
+    pub const fn reading(&self) -> &'static str {
+        NACCHIN[self.to_number() - 1].1
+    }
+
+    /// Returns the pair of `SexagenaryCycle` which shares the 納音.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::{Nacchin, SexagenaryCycle};
+    ///
+    /// assert_eq!(
+    ///     [SexagenaryCycle::KinoeNe, SexagenaryCycle::KinotoUshi],
+    ///     Nacchin::Kaichuukin.sexagenary_cycles()
+    /// );
+    /// ```
+    pub const fn sexagenary_cycles(&self) -> [SexagenaryCycle; 2] {
+        let num = self.to_number();
+        [
+            SexagenaryCycle::from_number(num * 2 - 1).unwrap(),
+            SexagenaryCycle::from_number(num * 2).unwrap(),
+        ]
+    }
+
+    /// Returns the number of 納音.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Nacchin;
+    ///
+    /// assert_eq!(1, Nacchin::Kaichuukin.to_number());
+    /// ```
+    pub const fn to_number(&self) -> usize {
+        match self {
+            Kaichuukin => 1,
+            Rochuuka => 2,
+            Dairinboku => 3,
+            Roboudo => 4,
+            Jinpoukin => 5,
+            Santouka => 6,
+            Kankasui => 7,
+            Joutoudo => 8,
+            Hakuroukin => 9,
+            Youryuuboku => 10,
+            Seisensui => 11,
+            Okujoudo => 12,
+            Hekirekika => 13,
+            Shouhakuboku => 14,
+            Chouryuusui => 15,
+            Sachuukin => 16,
+            Sangeka => 17,
+            Heichiboku => 18,
+            Hekijoudo => 19,
+            Kinpakukin => 20,
+            Fukutouka => 21,
+            Tengasui => 22,
+            Taiekido => 23,
+            Sasenkin => 24,
+            Soushakuboku => 25,
+            Daikeisui => 26,
+            Sachuudo => 27,
+            Tenjouka => 28,
+            Zakuroboku => 29,
+            Taikaisui => 30,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(SexagenaryCycle::KinoeNe, "海中金")]
+    #[case(SexagenaryCycle::KinotoUshi, "海中金")]
+    #[case(SexagenaryCycle::HinoeTora, "炉中火")]
+    #[case(SexagenaryCycle::KinoeTatsu, "覆燈火")]
+    #[case(SexagenaryCycle::HinoeUma, "天河水")]
+    #[case(SexagenaryCycle::MizunotoYi, "大海水")]
+    fn 干支から納音を導出できる(#[case] cycle: SexagenaryCycle, #[case] expect: &str) {
+        assert_eq!(expect, cycle.nacchin().name());
+    }
+
+    #[rstest]
+    fn 納音を共有する干支を導出できる() {
+        for num in 1..=30 {
+            let nacchin = Nacchin::from_number(num).unwrap();
+
+            for cycle in nacchin.sexagenary_cycles() {
+                assert_eq!(nacchin, cycle.nacchin());
+            }
+        }
+    }
+
+    #[rstest]
+    fn 納音の五行は名前の末尾と一致する() {
+        for num in 1..=30 {
+            let nacchin = Nacchin::from_number(num).unwrap();
+
+            assert!(nacchin.name().ends_with(nacchin.element().name()));
+            assert_eq!(Some(nacchin), Nacchin::from_name(nacchin.name()));
+            assert!(!nacchin.reading().is_empty());
+        }
+        assert!(Nacchin::from_number(0).is_none());
+        assert!(Nacchin::from_name("海中").is_none());
+    }
+}
@@ -0,0 +1,350 @@
+//! Conversion between numbers and Japanese numerals.
+//!
+//! Dates in official documents are often written in kanji numerals such as `令和六年十月十七日`
+//! or in full-width digits such as `令和６年１０月１７日`.
+//! This module converts numbers into these notations and back.
+//!
+//! # Example
+//!
+//! ```rust
+//! use koyomi_rs::numerals;
+//!
+//! assert_eq!("十七", numerals::to_kanji(17));
+//! assert_eq!("一七", numerals::to_positional_kanji(17));
+//! assert_eq!("壱拾漆", numerals::to_daiji(17));
+//! assert_eq!(Some(17), numerals::parse_number("十七"));
+//! assert_eq!("１７", numerals::to_full_width("17"));
+//! ```
+const DAIJI_DIGITS: [char; 10] = ['零', '壱', '弐', '参', '肆', '伍', '陸', '漆', '捌', '玖'];
+const DAIJI_UNITS: [char; 3] = ['拾', '佰', '阡'];
+const KANJI_DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+const KANJI_UNITS: [char; 3] = ['十', '百', '千'];
+const LARGE_UNITS: [char; 4] = ['万', '億', '兆', '京'];
+
+/// Parse a number written in digits or kanji numerals.
+///
+/// ASCII digits, full-width digits, kanji numerals in both plain and positional styles
+/// and 大字 (formal kanji numerals) are supported.
+/// Units which are repeated or out of order, such as `十十`, are not accepted.
+///
+/// # Example
+///
+/// ```rust
+/// use koyomi_rs::numerals::parse_number;
+///
+/// assert_eq!(Some(2024), parse_number("2024"));
+/// assert_eq!(Some(2024), parse_number("２０２４"));
+/// assert_eq!(Some(2024), parse_number("二千二十四"));
+/// assert_eq!(Some(2024), parse_number("二〇二四"));
+/// assert_eq!(Some(2024), parse_number("弐阡弐拾肆"));
+/// assert!(parse_number("二千a").is_none());
+/// assert!(parse_number("十十").is_none());
+/// ```
+pub fn parse_number(s: &str) -> Option<u64> {
+    if s.is_empty() {
+        return None;
+    }
+
+    let mut total = 0_u64;
+    let mut section = 0_u64;
+    let mut current: Option<u64> = None;
+    // The number of digits of `current`, which must be a single digit next to units.
+    let mut digits = 0;
+    // The units must appear only once and in descending order.
+    let mut last_small: Option<u64> = None;
+    let mut last_large: Option<u64> = None;
+
+    for c in s.chars() {
+        if let Some(digit) = to_digit(c) {
+            current = Some(current.unwrap_or(0).checked_mul(10)?.checked_add(digit)?);
+            digits += 1;
+        } else if let Some(unit) = small_unit(c) {
+            if digits > 1 || last_small.is_some_and(|l| l <= unit) {
+                return None;
+            }
+            section = section.checked_add(current.unwrap_or(1).checked_mul(unit)?)?;
+            current = None;
+            digits = 0;
+            last_small = Some(unit);
+        } else if let Some(unit) = large_unit(c) {
+            if (digits > 1 && last_small.is_some()) || last_large.is_some_and(|l| l <= unit) {
+                return None;
+            }
+            let value = match section.checked_add(current.unwrap_or(0))? {
+                // Only the leading unit can omit `一`, such as `万`.
+                0 if last_large.is_none() => 1,
+                0 => return None,
+                value => value,
+            };
+            total = total.checked_add(value.checked_mul(unit)?)?;
+            section = 0;
+            current = None;
+            digits = 0;
+            last_small = None;
+            last_large = Some(unit);
+        } else {
+            return None;
+        }
+    }
+
+    if digits > 1 && last_small.is_some() {
+        return None;
+    }
+
+    total
+        .checked_add(section)?
+        .checked_add(current.unwrap_or(0))
+}
+
+/// Convert a number into 大字 (formal kanji numerals) such as `壱拾漆`.
+///
+/// 大字 is used in legal documents to prevent falsification.
+///
+/// # Example
+///
+/// ```rust
+/// use koyomi_rs::numerals::to_daiji;
+///
+/// assert_eq!("壱拾漆", to_daiji(17));
+/// assert_eq!("弐阡弐拾肆", to_daiji(2024));
+/// assert_eq!("壱萬", to_daiji(10000));
+/// ```
+pub fn to_daiji(n: u64) -> String {
+    compose(n, &DAIJI_DIGITS, &DAIJI_UNITS, true).replace('万', "萬")
+}
+
+/// Convert digits into full-width digits.
+///
+/// The other characters are left as they are.
+///
+/// # Example
+///
+/// ```rust
+/// use koyomi_rs::numerals::to_full_width;
+///
+/// assert_eq!("令和６年１０月１７日", to_full_width("令和6年10月17日"));
+/// ```
+pub fn to_full_width(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '0'..='9' => char::from_u32(c as u32 - '0' as u32 + '０' as u32).unwrap(),
+            _ => c,
+        })
+        .collect()
+}
+
+/// Convert full-width digits into ASCII digits.
+///
+/// The other characters are left as they are.
+///
+/// # Example
+///
+/// ```rust
+/// use koyomi_rs::numerals::to_half_width;
+///
+/// assert_eq!("令和6年10月17日", to_half_width("令和６年１０月１７日"));
+/// ```
+pub fn to_half_width(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '０'..='９' => char::from_u32(c as u32 - '０' as u32 + '0' as u32).unwrap(),
+            _ => c,
+        })
+        .collect()
+}
+
+/// Convert a number into kanji numerals such as `十七`.
+///
+/// # Example
+///
+/// ```rust
+/// use koyomi_rs::numerals::to_kanji;
+///
+/// assert_eq!("十七", to_kanji(17));
+/// assert_eq!("二千二十四", to_kanji(2024));
+/// assert_eq!("一万二千三百四十五", to_kanji(12345));
+/// ```
+pub fn to_kanji(n: u64) -> String {
+    compose(n, &KANJI_DIGITS, &KANJI_UNITS, false)
+}
+
+/// Convert a number into positional kanji numerals such as `二〇二四`.
+///
+/// # Example
+///
+/// ```rust
+/// use koyomi_rs::numerals::to_positional_kanji;
+///
+/// assert_eq!("一七", to_positional_kanji(17));
+/// assert_eq!("二〇二四", to_positional_kanji(2024));
+/// ```
+pub fn to_positional_kanji(n: u64) -> String {
+    n.to_string()
+        .chars()
+        .map(|c| KANJI_DIGITS[c.to_digit(10).unwrap() as usize])
+        .collect()
+}
+
+fn compose(n: u64, digits: &[char; 10], units: &[char; 3], explicit_one: bool) -> String {
+    if n == 0 {
+        return digits[0].to_string();
+    }
+
+    let mut sections = Vec::new();
+    let mut rest = n;
+    while rest > 0 {
+        sections.push(rest % 10000);
+        rest /= 10000;
+    }
+
+    let mut result = String::new();
+    for (i, &section) in sections.iter().enumerate().rev() {
+        if section == 0 {
+            continue;
+        }
+        for (j, &unit) in units.iter().enumerate().rev() {
+            let digit = section / 10_u64.pow(j as u32 + 1) % 10;
+            if digit > 1 || (digit == 1 && explicit_one) {
+                result.push(digits[digit as usize]);
+            }
+            if digit > 0 {
+                result.push(unit);
+            }
+        }
+        if section % 10 > 0 {
+            result.push(digits[(section % 10) as usize]);
+        }
+        if i > 0 {
+            result.push(LARGE_UNITS[i - 1]);
+        }
+    }
+
+    result
+}
+
+fn large_unit(c: char) -> Option<u64> {
+    match c {
+        '万' | '萬' => Some(10_000),
+        '億' => Some(100_000_000),
+        '兆' => Some(1_000_000_000_000),
+        '京' => Some(10_000_000_000_000_000),
+        _ => None,
+    }
+}
+
+fn small_unit(c: char) -> Option<u64> {
+    match c {
+        '十' | '拾' => Some(10),
+        '百' | '佰' => Some(100),
+        '千' | '阡' | '仟' => Some(1000),
+        _ => None,
+    }
+}
+
+fn to_digit(c: char) -> Option<u64> {
+    match c {
+        '0'..='9' => Some(c as u64 - '0' as u64),
+        '０'..='９' => Some(c as u64 - '０' as u64),
+        '壹' => Some(1),
+        '貳' => Some(2),
+        '參' => Some(3),
+        _ => KANJI_DIGITS
+            .iter()
+            .chain(DAIJI_DIGITS.iter())
+            .position(|&k| k == c)
+            .map(|i| (i % 10) as u64),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("6", 6)]
+    #[case("31", 31)]
+    #[case("６４", 64)]
+    #[case("一", 1)]
+    #[case("十", 10)]
+    #[case("十七", 17)]
+    #[case("二十", 20)]
+    #[case("三十一", 31)]
+    #[case("二〇二四", 2024)]
+    #[case("千九百八十九", 1989)]
+    #[case("一万二千三百四十五", 12345)]
+    #[case("万", 10000)]
+    #[case("三億", 300_000_000)]
+    #[case("壱拾七", 17)]
+    #[case("弐阡弐拾肆", 2024)]
+    #[case("壹萬", 10000)]
+    #[case("3万", 30000)]
+    #[case("1234万5678", 12_345_678)]
+    #[case("一億二千万", 120_000_000)]
+    fn 数字を解析できる(#[case] s: &str, #[case] expect: u64) {
+        assert_eq!(Some(expect), parse_number(s));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("a")]
+    #[case("六a")]
+    #[case("九九九九九九九九九九九九九九九九九九九九九")]
+    #[case("十十")]
+    #[case("十百")]
+    #[case("一二十")]
+    #[case("十二三")]
+    #[case("一万万")]
+    #[case("万億")]
+    #[case("千23万")]
+    fn 数字でなければ解析できない(#[case] s: &str) {
+        assert!(parse_number(s).is_none());
+    }
+
+    #[rstest]
+    #[case(0, "〇")]
+    #[case(1, "一")]
+    #[case(10, "十")]
+    #[case(17, "十七")]
+    #[case(31, "三十一")]
+    #[case(100, "百")]
+    #[case(1989, "千九百八十九")]
+    #[case(2024, "二千二十四")]
+    #[case(10000, "一万")]
+    #[case(12345, "一万二千三百四十五")]
+    #[case(100_000_001, "一億一")]
+    fn 漢数字に変換できる(#[case] n: u64, #[case] expect: &str) {
+        assert_eq!(expect, to_kanji(n));
+        assert_eq!(Some(n), parse_number(expect));
+    }
+
+    #[rstest]
+    #[case(0, "〇")]
+    #[case(17, "一七")]
+    #[case(2024, "二〇二四")]
+    fn 位取りの漢数字に変換できる(#[case] n: u64, #[case] expect: &str) {
+        assert_eq!(expect, to_positional_kanji(n));
+        assert_eq!(Some(n), parse_number(expect));
+    }
+
+    #[rstest]
+    #[case(0, "零")]
+    #[case(1, "壱")]
+    #[case(10, "壱拾")]
+    #[case(17, "壱拾漆")]
+    #[case(2024, "弐阡弐拾肆")]
+    #[case(10000, "壱萬")]
+    fn 大字に変換できる(#[case] n: u64, #[case] expect: &str) {
+        assert_eq!(expect, to_daiji(n));
+        assert_eq!(Some(n), parse_number(expect));
+    }
+
+    #[rstest]
+    #[case("2024年10月17日", "２０２４年１０月１７日")]
+    #[case("R6.10.17", "R６.１０.１７")]
+    fn 全角と半角の数字を変換できる(#[case] half: &str, #[case] full: &str) {
+        assert_eq!(full, to_full_width(half));
+        assert_eq!(half, to_half_width(full));
+    }
+}
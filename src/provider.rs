@@ -0,0 +1,96 @@
+use std::fmt::Debug;
+
+use crate::day::JapaneseHoliday;
+
+use chrono::NaiveDate;
+
+/// Holiday
+///
+/// A holiday provided by [`HolidayProvider`].
+pub trait Holiday: Clone + Debug + Eq {
+    /// Returns the name of holiday.
+    fn name(&self) -> &str;
+}
+
+/// Holiday provider
+///
+/// A source of holidays used by [`JapaneseDate`](crate::JapaneseDate),
+/// [`Koyomi`](crate::Koyomi) and [`BusinessCalendar`](crate::BusinessCalendar).
+/// Japanese national holidays ([`NationalHolidays`]) are used by default.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use koyomi_rs::{Holiday, HolidayProvider, Koyomi};
+///
+/// #[derive(Clone, Debug, Eq, PartialEq)]
+/// struct MarketHoliday;
+///
+/// impl Holiday for MarketHoliday {
+///     fn name(&self) -> &str {
+///         "休場日"
+///     }
+/// }
+///
+/// #[derive(Debug)]
+/// struct Market;
+///
+/// impl HolidayProvider for Market {
+///     type Holiday = MarketHoliday;
+///
+///     fn holiday(&self, date: &NaiveDate) -> Option<Self::Holiday> {
+///         (date == &NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()).then_some(MarketHoliday)
+///     }
+/// }
+///
+/// let mut koyomi = Koyomi::month_of(2024, 12).unwrap().with_holidays(Market);
+///
+/// assert_eq!(Some("休場日"), koyomi.last().unwrap().custom_holiday_name());
+/// ```
+pub trait HolidayProvider {
+    /// Holiday type of the provider.
+    type Holiday: Holiday;
+
+    /// Returns the holiday of the date.
+    fn holiday(&self, date: &NaiveDate) -> Option<Self::Holiday>;
+}
+
+/// Japanese national holidays
+///
+/// The default [`HolidayProvider`] which provides [`JapaneseHoliday`].
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use koyomi_rs::{HolidayProvider, JapaneseHoliday, NationalHolidays};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+///
+/// assert_eq!(Some(JapaneseHoliday::NewYearsDay), NationalHolidays.holiday(&date));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct NationalHolidays;
+
+impl HolidayProvider for NationalHolidays {
+    type Holiday = JapaneseHoliday;
+
+    fn holiday(&self, date: &NaiveDate) -> Option<Self::Holiday> {
+        JapaneseHoliday::holiday(date)
+    }
+}
+
+impl<P: HolidayProvider> HolidayProvider for &P {
+    type Holiday = P::Holiday;
+
+    fn holiday(&self, date: &NaiveDate) -> Option<Self::Holiday> {
+        (**self).holiday(date)
+    }
+}
+
+impl Holiday for JapaneseHoliday {
+    fn name(&self) -> &str {
+        JapaneseHoliday::name(self)
+    }
+}
@@ -0,0 +1,214 @@
+use crate::internal::ROKUYO;
+use crate::lunisolar::LunisolarDate;
+use chrono::Datelike;
+
+use self::Rokuyo::*;
+
+/// Rokuyo (六曜)
+///
+/// It is derived from the month and day of the lunisolar calendar (旧暦).
+/// The first day of each lunisolar month starts from 先勝 for the 1st and 7th months,
+/// and shifts by one for every following month.
+/// A leap month (閏月) follows the same rokuyo as the previous month.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Rokuyo {
+    /// 先勝
+    Sensho,
+    /// 友引
+    Tomobiki,
+    /// 先負
+    Senbu,
+    /// 仏滅
+    Butsumetsu,
+    /// 大安
+    Taian,
+    /// 赤口
+    Shakko,
+}
+
+impl Rokuyo {
+    /// Generate from Datelike of chrono.
+    ///
+    /// Returns `None` if the date is out of the supported period of [`LunisolarDate`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::Rokuyo;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 10, 17).unwrap();
+    /// assert_eq!(Some(Rokuyo::Taian), Rokuyo::from_datelike(&date));
+    /// ```
+    pub fn from_datelike<T: Datelike>(date: &T) -> Option<Self> {
+        LunisolarDate::from_datelike(date).map(|d| Rokuyo::from_lunisolar(&d))
+    }
+
+    /// Generate from `LunisolarDate`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::{LunisolarDate, Rokuyo};
+    ///
+    /// let date = LunisolarDate::new(2024, 1, false, 1).unwrap();
+    /// assert_eq!(Rokuyo::Sensho, Rokuyo::from_lunisolar(&date));
+    /// ```
+    pub const fn from_lunisolar(date: &LunisolarDate) -> Self {
+        // 旧暦1月1日 is 先勝.
+        match Rokuyo::from_number(((date.month() + date.day() + 4) % 6) as usize + 1) {
+            Some(rokuyo) => rokuyo,
+            None => unreachable!(),
+        }
+    }
+
+    /// Generate from name of rokuyo.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Rokuyo;
+    ///
+    /// assert_eq!(Some(Rokuyo::Tomobiki), Rokuyo::from_name("友引"));
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        ROKUYO
+            .iter()
+            .position(|&(x, _)| x == name)
+            .and_then(|i| Rokuyo::from_number(i + 1))
+    }
+
+    /// Generate from number of rokuyo.
+    /// The index starts from `1` (先勝).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Rokuyo;
+    ///
+    /// assert_eq!(Some(Rokuyo::Sensho), Rokuyo::from_number(1));
+    /// ```
+    pub const fn from_number(num: usize) -> Option<Self> {
+        match num {
+            1 => Some(Sensho),
+            2 => Some(Tomobiki),
+            3 => Some(Senbu),
+            4 => Some(Butsumetsu),
+            5 => Some(Taian),
+            6 => Some(Shakko),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of rokuyo.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Rokuyo;
+    ///
+    /// assert_eq!("大安", Rokuyo::Taian.name());
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        ROKUYO[self.to_number() - 1].0
+    }
+
+    /// Returns the reading of rokuyo in hiragana.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Rokuyo;
+    ///
+    /// assert_eq!("ぶつめつ", Rokuyo::Butsumetsu.reading());
+    /// ```
+    pub const fn reading(&self) -> &'static str {
+        ROKUYO[self.to_number() - 1].1
+    }
+
+    /// Returns the number of rokuyo.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Rokuyo;
+    ///
+    /// assert_eq!(6, Rokuyo::Shakko.to_number());
+    /// ```
+    pub const fn to_number(&self) -> usize {
+        match self {
+            Sensho => 1,
+            Tomobiki => 2,
+            Senbu => 3,
+            Butsumetsu => 4,
+            Taian => 5,
+            Shakko => 6,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::NaiveDate;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case((2024, 2, 10), Sensho)]
+    #[case((2024, 2, 11), Tomobiki)]
+    #[case((2024, 2, 12), Senbu)]
+    #[case((2024, 2, 13), Butsumetsu)]
+    #[case((2024, 2, 14), Taian)]
+    #[case((2024, 2, 15), Shakko)]
+    #[case((2024, 9, 17), Butsumetsu)]
+    #[case((2024, 10, 17), Taian)]
+    #[case((2023, 3, 22), Tomobiki)]
+    fn 年月日から変換できる(#[case] date: (i32, u32, u32), #[case] expect: Rokuyo) {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+
+        assert_eq!(Some(expect), Rokuyo::from_datelike(&date));
+    }
+
+    #[rstest]
+    #[case(1, Sensho)]
+    #[case(2, Tomobiki)]
+    #[case(3, Senbu)]
+    #[case(4, Butsumetsu)]
+    #[case(5, Taian)]
+    #[case(6, Shakko)]
+    #[case(7, Sensho)]
+    #[case(12, Shakko)]
+    fn 旧暦の朔日の六曜は月によって決まる(
+        #[case] month: u32,
+        #[case] expect: Rokuyo,
+    ) {
+        let date = LunisolarDate::new(2024, month, false, 1).unwrap();
+
+        assert_eq!(expect, Rokuyo::from_lunisolar(&date));
+    }
+
+    #[rstest]
+    #[case(Sensho, "先勝", "せんしょう")]
+    #[case(Tomobiki, "友引", "ともびき")]
+    #[case(Senbu, "先負", "せんぶ")]
+    #[case(Butsumetsu, "仏滅", "ぶつめつ")]
+    #[case(Taian, "大安", "たいあん")]
+    #[case(Shakko, "赤口", "しゃっこう")]
+    fn 名前と読みを導出できる(
+        #[case] rokuyo: Rokuyo,
+        #[case] name: &str,
+        #[case] reading: &str,
+    ) {
+        assert_eq!(name, rokuyo.name());
+        assert_eq!(reading, rokuyo.reading());
+        assert_eq!(Some(rokuyo), Rokuyo::from_name(name));
+    }
+
+    #[rstest]
+    fn 六曜の名前や番号でなければ変換できない() {
+        assert!(Rokuyo::from_name("吉日").is_none());
+        assert!(Rokuyo::from_number(0).is_none());
+        assert!(Rokuyo::from_number(7).is_none());
+    }
+}
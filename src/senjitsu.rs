@@ -0,0 +1,212 @@
+use crate::internal::day_cycle_index;
+use crate::lunisolar::LunisolarDate;
+use crate::year::solar_month;
+use chrono::Datelike;
+
+use self::Senjitsu::*;
+
+/// Auspicious and inauspicious days (選日)
+///
+/// Days marked on the traditional Japanese almanac other than 六曜,
+/// derived from the sexagenary cycle of the day and the month which begins at each 節.
+/// Only 不成就日 is derived from the month of the lunisolar calendar.
+///
+/// The variants are declared in the order in which they are usually printed on calendars.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Senjitsu {
+    /// 一粒万倍日
+    Ichiryumanbaibi,
+    /// 天赦日
+    Tenshanichi,
+    /// 不成就日
+    Fujojubi,
+    /// 三隣亡
+    Sanrinbo,
+    /// 寅の日
+    ToraNoHi,
+    /// 己巳の日
+    TsuchinotoMiNoHi,
+    /// 十方暮
+    Jippogure,
+}
+
+impl Senjitsu {
+    /// Generate from Datelike of chrono.
+    ///
+    /// Returns all of the days on the date, since they often overlap.
+    /// 不成就日 is not derived if the date is out of the supported period of [`LunisolarDate`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::Senjitsu;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    ///
+    /// assert_eq!(
+    ///     vec![Senjitsu::Ichiryumanbaibi, Senjitsu::Tenshanichi],
+    ///     Senjitsu::from_datelike(&date)
+    /// );
+    /// ```
+    pub fn from_datelike<T: Datelike>(date: &T) -> Vec<Self> {
+        Senjitsu::from_datelike_with_lunisolar(date, LunisolarDate::from_datelike(date).as_ref())
+    }
+
+    /// Generate from name of the day.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Senjitsu;
+    ///
+    /// assert_eq!(Some(Senjitsu::Tenshanichi), Senjitsu::from_name("天赦日"));
+    /// assert!(Senjitsu::from_name("大安").is_none());
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "一粒万倍日" => Some(Ichiryumanbaibi),
+            "天赦日" => Some(Tenshanichi),
+            "不成就日" => Some(Fujojubi),
+            "三隣亡" => Some(Sanrinbo),
+            "寅の日" => Some(ToraNoHi),
+            "己巳の日" => Some(TsuchinotoMiNoHi),
+            "十方暮" => Some(Jippogure),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the day.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Senjitsu;
+    ///
+    /// assert_eq!("一粒万倍日", Senjitsu::Ichiryumanbaibi.name());
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Fujojubi => "不成就日",
+            Ichiryumanbaibi => "一粒万倍日",
+            Jippogure => "十方暮",
+            Sanrinbo => "三隣亡",
+            Tenshanichi => "天赦日",
+            ToraNoHi => "寅の日",
+            TsuchinotoMiNoHi => "己巳の日",
+        }
+    }
+
+    /// Returns the reading of the day in hiragana.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Senjitsu;
+    ///
+    /// assert_eq!("てんしゃにち", Senjitsu::Tenshanichi.reading());
+    /// ```
+    pub const fn reading(&self) -> &'static str {
+        match self {
+            Fujojubi => "ふじょうじゅび",
+            Ichiryumanbaibi => "いちりゅうまんばいび",
+            Jippogure => "じっぽうぐれ",
+            Sanrinbo => "さんりんぼう",
+            Tenshanichi => "てんしゃにち",
+            ToraNoHi => "とらのひ",
+            TsuchinotoMiNoHi => "つちのとみのひ",
+        }
+    }
+
+    /// Generate from Datelike of chrono and the lunisolar date derived from the same date.
+    pub(crate) fn from_datelike_with_lunisolar<T: Datelike>(
+        date: &T,
+        lunisolar: Option<&LunisolarDate>,
+    ) -> Vec<Self> {
+        let index = day_cycle_index(date);
+        let (stem, branch) = (index % 10, index % 12);
+        let (_, month) = solar_month(date);
+
+        let mut days = Vec::new();
+
+        // The earthly branches of the day for each month from 寅 month.
+        #[rustfmt::skip]
+        const ICHIRYUMANBAIBI: [[usize; 2]; 12] = [
+            [1, 6], [2, 9], [0, 3], [3, 4], [5, 6], [6, 9],
+            [0, 7], [3, 8], [6, 9], [9, 10], [0, 11], [0, 3],
+        ];
+        if ICHIRYUMANBAIBI[month].contains(&branch) {
+            days.push(Ichiryumanbaibi);
+        }
+
+        // 戊寅 in spring, 甲午 in summer, 戊申 in autumn and 甲子 in winter.
+        if index == [14, 30, 44, 0][month / 3] {
+            days.push(Tenshanichi);
+        }
+
+        // Every 8 days from the 3rd, 2nd, 1st, 4th, 5th and 6th day
+        // of the 1st to 6th lunar months, and likewise of the 7th to 12th.
+        if lunisolar.is_some_and(|l| l.day() % 8 == [6, 3, 2, 1, 4, 5][l.month() as usize % 6]) {
+            days.push(Fujojubi);
+        }
+
+        // 亥, 寅 and 午 in turn from 寅 month.
+        if branch == [11, 2, 6][month % 3] {
+            days.push(Sanrinbo);
+        }
+
+        if branch == 2 {
+            days.push(ToraNoHi);
+        }
+        // 己 is the 6th of the heavenly stems and 巳 is the 6th of the earthly branches.
+        if stem == 5 && branch == 5 {
+            days.push(TsuchinotoMiNoHi);
+        }
+        // From 甲申 until 癸巳
+        if (20..30).contains(&index) {
+            days.push(Jippogure);
+        }
+
+        days
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::NaiveDate;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case((2024, 1, 1), vec![Ichiryumanbaibi, Tenshanichi])]
+    #[case((2024, 1, 2), vec![Fujojubi])]
+    #[case((2024, 1, 3), vec![Sanrinbo, ToraNoHi])]
+    #[case((2024, 1, 4), vec![])]
+    #[case((2024, 1, 6), vec![TsuchinotoMiNoHi])]
+    #[case((2024, 1, 7), vec![Sanrinbo])]
+    #[case((2024, 1, 24), vec![Fujojubi, Jippogure])]
+    #[case((2024, 2, 12), vec![Ichiryumanbaibi, Fujojubi])]
+    #[case((2024, 3, 15), vec![Ichiryumanbaibi, Tenshanichi, Sanrinbo, ToraNoHi])]
+    #[case((2024, 10, 11), vec![Tenshanichi, Fujojubi])]
+    fn 年月日から変換できる(
+        #[case] date: (i32, u32, u32),
+        #[case] expect: Vec<Senjitsu>,
+    ) {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+
+        assert_eq!(expect, Senjitsu::from_datelike(&date));
+    }
+
+    #[rstest]
+    #[case(Ichiryumanbaibi)]
+    #[case(Tenshanichi)]
+    #[case(Fujojubi)]
+    #[case(Sanrinbo)]
+    #[case(ToraNoHi)]
+    #[case(TsuchinotoMiNoHi)]
+    #[case(Jippogure)]
+    fn 名前から変換できる(#[case] senjitsu: Senjitsu) {
+        assert_eq!(Some(senjitsu), Senjitsu::from_name(senjitsu.name()));
+    }
+}
@@ -0,0 +1,266 @@
+use crate::internal::SHUKU;
+use chrono::Datelike;
+
+use self::Shuku::*;
+
+/// Twenty-eight lunar mansions (二十八宿)
+///
+/// The mansions are assigned to each day in order regardless of the months,
+/// so that 角 always falls on Thursday.
+///
+/// The variants are named after the Japanese names (和名) of the mansions,
+/// since some of the mansions share the same reading.
+///
+/// You can convert between the order of the mansions,
+/// noting that it starts from `1` (角).
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Shuku {
+    /// 角
+    Suboshi,
+    /// 亢
+    Amiboshi,
+    /// 氐
+    Tomoboshi,
+    /// 房
+    Soiboshi,
+    /// 心
+    Nakagoboshi,
+    /// 尾
+    Ashitareboshi,
+    /// 箕
+    Miboshi,
+    /// 斗
+    Hikitsuboshi,
+    /// 牛
+    Inamiboshi,
+    /// 女
+    Urukiboshi,
+    /// 虚
+    Tomiteboshi,
+    /// 危
+    Umiyameboshi,
+    /// 室
+    Hatsuiboshi,
+    /// 壁
+    Namameboshi,
+    /// 奎
+    Tokakiboshi,
+    /// 婁
+    Tataraboshi,
+    /// 胃
+    Ekieboshi,
+    /// 昴
+    Subaruboshi,
+    /// 畢
+    Amefuriboshi,
+    /// 觜
+    Torokiboshi,
+    /// 参
+    Karasukiboshi,
+    /// 井
+    Chichiriboshi,
+    /// 鬼
+    Tamaonoboshi,
+    /// 柳
+    Nurikoboshi,
+    /// 星
+    Hotooriboshi,
+    /// 張
+    Chirikoboshi,
+    /// 翼
+    Tasukiboshi,
+    /// 軫
+    Mitsukakeboshi,
+}
+
+impl Shuku {
+    /// Generate from Datelike of chrono.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::Shuku;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+    /// assert_eq!(Shuku::Ekieboshi, Shuku::from_datelike(&date));
+    /// ```
+    pub fn from_datelike<T: Datelike>(date: &T) -> Self {
+        // 2000-01-01 is 胃, the 17th mansion.
+        Shuku::from_number((date.num_days_from_ce() + 24).rem_euclid(28) as usize + 1).unwrap()
+    }
+
+    /// Generate from name of mansion.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Shuku;
+    ///
+    /// assert_eq!(Some(Shuku::Mitsukakeboshi), Shuku::from_name("軫"));
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        SHUKU
+            .iter()
+            .position(|&(x, _)| x == name)
+            .and_then(|i| Shuku::from_number(i + 1))
+    }
+
+    /// Generate from number of mansion.
+    /// The index starts from `1` (角).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Shuku;
+    ///
+    /// assert_eq!(Some(Shuku::Suboshi), Shuku::from_number(1));
+    /// assert!(Shuku::from_number(29).is_none());
+    /// ```
+    pub const fn from_number(num: usize) -> Option<Self> {
+        match num {
+            1 => Some(Suboshi),
+            2 => Some(Amiboshi),
+            3 => Some(Tomoboshi),
+            4 => Some(Soiboshi),
+            5 => Some(Nakagoboshi),
+            6 => Some(Ashitareboshi),
+            7 => Some(Miboshi),
+            8 => Some(Hikitsuboshi),
+            9 => Some(Inamiboshi),
+            10 => Some(Urukiboshi),
+            11 => Some(Tomiteboshi),
+            12 => Some(Umiyameboshi),
+            13 => Some(Hatsuiboshi),
+            14 => Some(Namameboshi),
+            15 => Some(Tokakiboshi),
+            16 => Some(Tataraboshi),
+            17 => Some(Ekieboshi),
+            18 => Some(Subaruboshi),
+            19 => Some(Amefuriboshi),
+            20 => Some(Torokiboshi),
+            21 => Some(Karasukiboshi),
+            22 => Some(Chichiriboshi),
+            23 => Some(Tamaonoboshi),
+            24 => Some(Nurikoboshi),
+            25 => Some(Hotooriboshi),
+            26 => Some(Chirikoboshi),
+            27 => Some(Tasukiboshi),
+            28 => Some(Mitsukakeboshi),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of mansion.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Shuku;
+    ///
+    /// assert_eq!("角", Shuku::Suboshi.name());
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        SHUKU[self.to_number() - 1].0
+    }
+
+    /// Returns the reading of mansion in hiragana.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Shuku;
+    ///
+    /// assert_eq!("ひつ", Shuku::Amefuriboshi.reading());
+    /// ```
+    pub const fn reading(&self) -> &'static str {
+        SHUKU[self.to_number() - 1].1
+    }
+
+    /// Returns the number of mansion.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Shuku;
+    ///
+    /// assert_eq!(1, Shuku::Suboshi.to_number());
+    /// ```
+    pub const fn to_number(&self) -> usize {
+        match self {
+            Suboshi => 1,
+            Amiboshi => 2,
+            Tomoboshi => 3,
+            Soiboshi => 4,
+            Nakagoboshi => 5,
+            Ashitareboshi => 6,
+            Miboshi => 7,
+            Hikitsuboshi => 8,
+            Inamiboshi => 9,
+            Urukiboshi => 10,
+            Tomiteboshi => 11,
+            Umiyameboshi => 12,
+            Hatsuiboshi => 13,
+            Namameboshi => 14,
+            Tokakiboshi => 15,
+            Tataraboshi => 16,
+            Ekieboshi => 17,
+            Subaruboshi => 18,
+            Amefuriboshi => 19,
+            Torokiboshi => 20,
+            Karasukiboshi => 21,
+            Chichiriboshi => 22,
+            Tamaonoboshi => 23,
+            Nurikoboshi => 24,
+            Hotooriboshi => 25,
+            Chirikoboshi => 26,
+            Tasukiboshi => 27,
+            Mitsukakeboshi => 28,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::{NaiveDate, Weekday};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case((2000, 1, 1), "胃")]
+    #[case((2024, 1, 1), "畢")]
+    #[case((2024, 1, 2), "觜")]
+    #[case((2024, 1, 11), "角")]
+    #[case((2024, 2, 7), "軫")]
+    #[case((2024, 2, 8), "角")]
+    fn 年月日から変換できる(#[case] date: (i32, u32, u32), #[case] expect: &str) {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+
+        assert_eq!(expect, Shuku::from_datelike(&date).name());
+    }
+
+    #[rstest]
+    fn 角宿は木曜日になる() {
+        let first = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+
+        for date in first.iter_days().take(365) {
+            if Shuku::from_datelike(&date) == Suboshi {
+                assert_eq!(Weekday::Thu, date.weekday());
+            }
+        }
+    }
+
+    #[rstest]
+    fn 名前と読みと番号を相互に変換できる() {
+        for num in 1..=28 {
+            let shuku = Shuku::from_number(num).unwrap();
+
+            assert_eq!(num, shuku.to_number());
+            assert_eq!(Some(shuku), Shuku::from_name(shuku.name()));
+            assert!(!shuku.reading().is_empty());
+        }
+        assert!(Shuku::from_number(0).is_none());
+        assert!(Shuku::from_name("月").is_none());
+    }
+}
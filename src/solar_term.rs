@@ -0,0 +1,468 @@
+use crate::astronomy::{jde_from_date, jst_from_jde, solar_longitude, solar_longitude_jde};
+use crate::internal::{SOLAR_TERMS, to_naive_date};
+use chrono::{Datelike, NaiveDateTime};
+
+use self::SolarTerm::*;
+
+/// Twenty-four solar terms (二十四節気)
+///
+/// The year is divided into 24 terms every 15 degrees of the apparent solar longitude,
+/// starting from 立春 (315 degrees).
+///
+/// The date of each term is the date in JST when the sun reaches its longitude.
+/// You can convert between the order of the solar terms,
+/// noting that it starts from `1` (立春).
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum SolarTerm {
+    /// 立春
+    Risshun,
+    /// 雨水
+    Usui,
+    /// 啓蟄
+    Keichitsu,
+    /// 春分
+    Shunbun,
+    /// 清明
+    Seimei,
+    /// 穀雨
+    Kokuu,
+    /// 立夏
+    Rikka,
+    /// 小満
+    Shouman,
+    /// 芒種
+    Boushu,
+    /// 夏至
+    Geshi,
+    /// 小暑
+    Shousho,
+    /// 大暑
+    Taisho,
+    /// 立秋
+    Risshuu,
+    /// 処暑
+    Shosho,
+    /// 白露
+    Hakuro,
+    /// 秋分
+    Shuubun,
+    /// 寒露
+    Kanro,
+    /// 霜降
+    Soukou,
+    /// 立冬
+    Rittou,
+    /// 小雪
+    Shousetsu,
+    /// 大雪
+    Taisetsu,
+    /// 冬至
+    Touji,
+    /// 小寒
+    Shoukan,
+    /// 大寒
+    Daikan,
+}
+
+impl SolarTerm {
+    /// Returns the date and time in JST when the solar term begins in the specified year.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::SolarTerm;
+    ///
+    /// let risshun = SolarTerm::Risshun.datetime(2024).unwrap();
+    ///
+    /// assert_eq!(NaiveDate::from_ymd_opt(2024, 2, 4).unwrap(), risshun.date());
+    /// ```
+    pub fn datetime(&self, year: i32) -> Option<NaiveDateTime> {
+        solar_longitude_jde(year, self.longitude() as f64).and_then(jst_from_jde)
+    }
+
+    /// Generate from Datelike of chrono.
+    ///
+    /// Returns `None` unless a solar term begins on the date.
+    /// Use [`SolarTerm::period_of`] for the solar term which the date belongs to.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::SolarTerm;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 6, 21).unwrap();
+    /// assert_eq!(Some(SolarTerm::Geshi), SolarTerm::from_datelike(&date));
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 6, 22).unwrap();
+    /// assert!(SolarTerm::from_datelike(&date).is_none());
+    /// ```
+    pub fn from_datelike<T: Datelike>(date: &T) -> Option<Self> {
+        let jde = jde_from_date(&to_naive_date(date));
+        let start = (solar_longitude(jde) / 15.0).floor() as usize;
+        let end = (solar_longitude(jde + 1.0) / 15.0).floor() as usize;
+
+        (start != end).then(|| from_longitude_index(end))
+    }
+
+    /// Generate from name of solar term.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::SolarTerm;
+    ///
+    /// assert_eq!(Some(SolarTerm::Touji), SolarTerm::from_name("冬至"));
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        SOLAR_TERMS
+            .iter()
+            .position(|&(x, _)| x == name)
+            .and_then(|i| SolarTerm::from_number(i + 1))
+    }
+
+    /// Generate from number of solar term.
+    /// The index starts from `1` (立春).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::SolarTerm;
+    ///
+    /// assert_eq!(Some(SolarTerm::Risshun), SolarTerm::from_number(1));
+    /// ```
+    pub const fn from_number(num: usize) -> Option<Self> {
+        match num {
+            1 => Some(Risshun),
+            2 => Some(Usui),
+            3 => Some(Keichitsu),
+            4 => Some(Shunbun),
+            5 => Some(Seimei),
+            6 => Some(Kokuu),
+            7 => Some(Rikka),
+            8 => Some(Shouman),
+            9 => Some(Boushu),
+            10 => Some(Geshi),
+            11 => Some(Shousho),
+            12 => Some(Taisho),
+            13 => Some(Risshuu),
+            14 => Some(Shosho),
+            15 => Some(Hakuro),
+            16 => Some(Shuubun),
+            17 => Some(Kanro),
+            18 => Some(Soukou),
+            19 => Some(Rittou),
+            20 => Some(Shousetsu),
+            21 => Some(Taisetsu),
+            22 => Some(Touji),
+            23 => Some(Shoukan),
+            24 => Some(Daikan),
+            _ => None,
+        }
+    }
+
+    /// Returns the apparent solar longitude in degrees when the solar term begins.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::SolarTerm;
+    ///
+    /// assert_eq!(315, SolarTerm::Risshun.longitude());
+    /// assert_eq!(0, SolarTerm::Shunbun.longitude());
+    /// ```
+    pub const fn longitude(&self) -> u32 {
+        ((self.to_number() as u32 - 1) * 15 + 315) % 360
+    }
+
+    /// Returns the name of solar term.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::SolarTerm;
+    ///
+    /// assert_eq!("立春", SolarTerm::Risshun.name());
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        SOLAR_TERMS[self.to_number() - 1].0
+    }
+
+    /// Returns the first solar term after the specified date
+    /// with the date and time in JST when it begins.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::SolarTerm;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    /// let (datetime, term) = SolarTerm::next_after(&date).unwrap();
+    ///
+    /// assert_eq!(SolarTerm::Shoukan, term);
+    /// assert_eq!(NaiveDate::from_ymd_opt(2025, 1, 5).unwrap(), datetime.date());
+    /// ```
+    pub fn next_after<T: Datelike>(date: &T) -> Option<(NaiveDateTime, Self)> {
+        let date = to_naive_date(date);
+        let next = SolarTerm::from_number(SolarTerm::period_of(&date).to_number() % 24 + 1)?;
+
+        match next.datetime(date.year())? {
+            datetime if datetime.date() > date => Some((datetime, next)),
+            _ => next
+                .datetime(date.year() + 1)
+                .map(|datetime| (datetime, next)),
+        }
+    }
+
+    /// Returns the solar term which the date belongs to.
+    ///
+    /// It is the last solar term which begins on or before the date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::SolarTerm;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 10, 17).unwrap();
+    /// assert_eq!(SolarTerm::Kanro, SolarTerm::period_of(&date));
+    /// ```
+    pub fn period_of<T: Datelike>(date: &T) -> Self {
+        let jde = jde_from_date(&to_naive_date(date)) + 1.0;
+
+        from_longitude_index((solar_longitude(jde) / 15.0).floor() as usize)
+    }
+
+    /// Returns the reading of solar term in hiragana.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::SolarTerm;
+    ///
+    /// assert_eq!("りっしゅん", SolarTerm::Risshun.reading());
+    /// ```
+    pub const fn reading(&self) -> &'static str {
+        SOLAR_TERMS[self.to_number() - 1].1
+    }
+
+    /// Returns the solar terms of the specified year in date order
+    /// with the date and time in JST when they begin.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::SolarTerm;
+    ///
+    /// let terms = SolarTerm::terms_in_year(2024);
+    ///
+    /// assert_eq!(24, terms.len());
+    /// assert_eq!(SolarTerm::Shoukan, terms[0].1);
+    /// assert_eq!(NaiveDate::from_ymd_opt(2024, 1, 6).unwrap(), terms[0].0.date());
+    /// ```
+    pub fn terms_in_year(year: i32) -> Vec<(NaiveDateTime, Self)> {
+        let mut terms: Vec<(NaiveDateTime, Self)> = (1..=24)
+            .filter_map(SolarTerm::from_number)
+            .filter_map(|term| term.datetime(year).map(|datetime| (datetime, term)))
+            .collect();
+        terms.sort();
+
+        terms
+    }
+
+    /// Returns the number of solar term.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::SolarTerm;
+    ///
+    /// assert_eq!(24, SolarTerm::Daikan.to_number());
+    /// ```
+    pub const fn to_number(&self) -> usize {
+        match self {
+            Risshun => 1,
+            Usui => 2,
+            Keichitsu => 3,
+            Shunbun => 4,
+            Seimei => 5,
+            Kokuu => 6,
+            Rikka => 7,
+            Shouman => 8,
+            Boushu => 9,
+            Geshi => 10,
+            Shousho => 11,
+            Taisho => 12,
+            Risshuu => 13,
+            Shosho => 14,
+            Hakuro => 15,
+            Shuubun => 16,
+            Kanro => 17,
+            Soukou => 18,
+            Rittou => 19,
+            Shousetsu => 20,
+            Taisetsu => 21,
+            Touji => 22,
+            Shoukan => 23,
+            Daikan => 24,
+        }
+    }
+}
+
+fn from_longitude_index(index: usize) -> SolarTerm {
+    // The index is counted from 春分 (0 degrees), which is the 4th solar term.
+    SolarTerm::from_number((index + 3) % 24 + 1).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::NaiveDate;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1, 6, Shoukan)]
+    #[case(1, 20, Daikan)]
+    #[case(2, 4, Risshun)]
+    #[case(2, 19, Usui)]
+    #[case(3, 5, Keichitsu)]
+    #[case(3, 20, Shunbun)]
+    #[case(4, 4, Seimei)]
+    #[case(4, 19, Kokuu)]
+    #[case(5, 5, Rikka)]
+    #[case(5, 20, Shouman)]
+    #[case(6, 5, Boushu)]
+    #[case(6, 21, Geshi)]
+    #[case(7, 6, Shousho)]
+    #[case(7, 22, Taisho)]
+    #[case(8, 7, Risshuu)]
+    #[case(8, 22, Shosho)]
+    #[case(9, 7, Hakuro)]
+    #[case(9, 22, Shuubun)]
+    #[case(10, 8, Kanro)]
+    #[case(10, 23, Soukou)]
+    #[case(11, 7, Rittou)]
+    #[case(11, 22, Shousetsu)]
+    #[case(12, 7, Taisetsu)]
+    #[case(12, 21, Touji)]
+    fn 節気の日付を判定できる(
+        #[case] m: u32,
+        #[case] d: u32,
+        #[case] expect: SolarTerm,
+    ) {
+        let date = NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+
+        assert_eq!(Some(expect), SolarTerm::from_datelike(&date));
+        assert_eq!(expect, SolarTerm::period_of(&date));
+    }
+
+    #[rstest]
+    #[case(2024, 2, 3)]
+    #[case(2024, 2, 5)]
+    #[case(2024, 10, 17)]
+    fn 節気の日付でなければ判定できない(
+        #[case] y: i32,
+        #[case] m: u32,
+        #[case] d: u32,
+    ) {
+        let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert!(SolarTerm::from_datelike(&date).is_none());
+    }
+
+    #[rstest]
+    #[case(2024, 2, 3, Daikan)]
+    #[case(2024, 2, 5, Risshun)]
+    #[case(2024, 12, 31, Touji)]
+    #[case(2025, 1, 1, Touji)]
+    fn 日付が属する節気を判定できる(
+        #[case] y: i32,
+        #[case] m: u32,
+        #[case] d: u32,
+        #[case] expect: SolarTerm,
+    ) {
+        let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(expect, SolarTerm::period_of(&date));
+    }
+
+    #[rstest]
+    #[case(Risshun, 2024, (2, 4, 17, 27))]
+    #[case(Shunbun, 2024, (3, 20, 12, 6))]
+    #[case(Geshi, 2024, (6, 21, 5, 51))]
+    #[case(Touji, 2024, (12, 21, 18, 20))]
+    #[case(Daikan, 2025, (1, 20, 5, 0))]
+    #[case(Risshun, 2025, (2, 3, 23, 10))]
+    fn 節気の時刻を導出できる(
+        #[case] term: SolarTerm,
+        #[case] year: i32,
+        #[case] expect: (u32, u32, u32, u32),
+    ) {
+        let (m, d, h, min) = expect;
+        let expect = NaiveDate::from_ymd_opt(year, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap();
+        let datetime = term.datetime(year).unwrap();
+
+        assert!((datetime - expect).num_seconds().abs() < 60);
+    }
+
+    #[rstest]
+    fn 一年の節気を日付順に列挙できる() {
+        let terms = SolarTerm::terms_in_year(2024);
+        let names: Vec<&str> = terms.iter().map(|(_, term)| term.name()).collect();
+
+        assert_eq!(24, terms.len());
+        assert_eq!(
+            vec![
+                "小寒", "大寒", "立春", "雨水", "啓蟄", "春分", "清明", "穀雨", "立夏", "小満",
+                "芒種", "夏至", "小暑", "大暑", "立秋", "処暑", "白露", "秋分", "寒露", "霜降",
+                "立冬", "小雪", "大雪", "冬至",
+            ],
+            names
+        );
+        assert!(terms.iter().all(|(datetime, _)| datetime.year() == 2024));
+    }
+
+    #[rstest]
+    #[case((2024, 2, 3), ((2024, 2, 4), Risshun))]
+    #[case((2024, 2, 4), ((2024, 2, 19), Usui))]
+    #[case((2024, 12, 21), ((2025, 1, 5), Shoukan))]
+    #[case((2024, 12, 31), ((2025, 1, 5), Shoukan))]
+    fn 次の節気を導出できる(
+        #[case] date: (i32, u32, u32),
+        #[case] expect: ((i32, u32, u32), SolarTerm),
+    ) {
+        let (y, m, d) = date;
+        let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let ((y, m, d), term) = expect;
+        let expect = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let (datetime, actual) = SolarTerm::next_after(&date).unwrap();
+
+        assert_eq!(term, actual);
+        assert_eq!(expect, datetime.date());
+    }
+
+    #[rstest]
+    fn 名前と読みと番号を相互に変換できる() {
+        for num in 1..=24 {
+            let term = SolarTerm::from_number(num).unwrap();
+
+            assert_eq!(num, term.to_number());
+            assert_eq!(Some(term), SolarTerm::from_name(term.name()));
+            assert!(!term.reading().is_empty());
+        }
+        assert!(SolarTerm::from_number(0).is_none());
+        assert!(SolarTerm::from_number(25).is_none());
+        assert!(SolarTerm::from_name("立春分").is_none());
+        assert_eq!("しゅうぶん", Shuubun.reading());
+        assert_eq!(180, Shuubun.longitude());
+        assert_eq!(270, Touji.longitude());
+    }
+}
@@ -1,9 +1,135 @@
-use crate::internal::{HEAVENLY_STEMS, JAPANESE_ZODIAC, SEXAGENARY_CYCLE};
-use chrono::Datelike;
+use crate::internal::{
+    HEAVENLY_STEM_READINGS, HEAVENLY_STEMS, JAPANESE_ZODIAC, JAPANESE_ZODIAC_ANIMALS,
+    JAPANESE_ZODIAC_DIRECTIONS, JAPANESE_ZODIAC_READINGS, NINE_STARS, SEXAGENARY_CYCLE,
+    SEXAGENARY_CYCLE_READINGS, day_cycle_index, to_naive_date,
+};
+use crate::nacchin::Nacchin;
+use crate::solar_term::SolarTerm;
+use chrono::{Datelike, Days, Timelike};
 
+use self::FiveElement::*;
 use self::HeavenlyStem::*;
 use self::JapaneseZodiac::*;
+use self::NineStar::*;
 use self::SexagenaryCycle::*;
+use self::YearBoundary::*;
+use self::YinYang::*;
+
+/// Boundary of the year for the heavenly stem, the Japanese zodiac and the sexagenary cycle.
+///
+/// In fortune-telling and traditional almanacs, the year changes at 立春 (around February 4)
+/// instead of January 1.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum YearBoundary {
+    /// January 1
+    #[default]
+    NewYearsDay,
+    /// 立春
+    Risshun,
+}
+
+/// Five elements (五行)
+///
+/// Each of the heavenly stems and the Japanese zodiac belongs to one of them.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum FiveElement {
+    /// 木
+    Wood,
+    /// 火
+    Fire,
+    /// 土
+    Earth,
+    /// 金
+    Metal,
+    /// 水
+    Water,
+}
+
+impl FiveElement {
+    /// Returns the name of five element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::FiveElement;
+    ///
+    /// assert_eq!("金", FiveElement::Metal.name());
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Earth => "土",
+            Fire => "火",
+            Metal => "金",
+            Water => "水",
+            Wood => "木",
+        }
+    }
+
+    /// Returns the reading of five element in hiragana.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::FiveElement;
+    ///
+    /// assert_eq!("ごん", FiveElement::Metal.reading());
+    /// ```
+    pub const fn reading(&self) -> &'static str {
+        match self {
+            Earth => "ど",
+            Fire => "か",
+            Metal => "ごん",
+            Water => "すい",
+            Wood => "もく",
+        }
+    }
+}
+
+/// Yin and yang (陰陽)
+///
+/// In the heavenly stems, 陽 is called 兄 (え) and 陰 is called 弟 (と),
+/// such as 甲 (木の兄) and 乙 (木の弟).
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum YinYang {
+    /// 陽
+    Yang,
+    /// 陰
+    Yin,
+}
+
+impl YinYang {
+    /// Returns the name of yin and yang.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::YinYang;
+    ///
+    /// assert_eq!("陽", YinYang::Yang.name());
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Yang => "陽",
+            Yin => "陰",
+        }
+    }
+
+    /// Returns 兄 for 陽 and 弟 for 陰.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::YinYang;
+    ///
+    /// assert_eq!("弟", YinYang::Yin.sibling_name());
+    /// ```
+    pub const fn sibling_name(&self) -> &'static str {
+        match self {
+            Yang => "兄",
+            Yin => "弟",
+        }
+    }
+}
 
 /// Heavenly stem.
 ///
@@ -36,6 +162,26 @@ pub enum HeavenlyStem {
 }
 
 impl HeavenlyStem {
+    /// Returns the `FiveElement` of heavenly stem.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::{FiveElement, HeavenlyStem};
+    ///
+    /// assert_eq!(FiveElement::Wood, HeavenlyStem::Kinoe.element());
+    /// assert_eq!(FiveElement::Water, HeavenlyStem::Mizunoto.element());
+    /// ```
+    pub const fn element(&self) -> FiveElement {
+        match self {
+            Kinoe | Kinoto => Wood,
+            Hinoe | Hinoto => Fire,
+            Tsuchinoe | Tsuchinoto => Earth,
+            Kanoe | Kanoto => Metal,
+            Mizunoe | Mizunoto => Water,
+        }
+    }
+
     /// Generate from Datelike of chrono.
     ///
     /// # Example
@@ -48,8 +194,25 @@ impl HeavenlyStem {
     /// assert_eq!(HeavenlyStem::Kinoe, HeavenlyStem::from_datelike(&date));
     /// ```
     pub fn from_datelike<T: Datelike>(date: &T) -> Self {
+        HeavenlyStem::from_datelike_with_boundary(date, NewYearsDay)
+    }
+
+    /// Generate from Datelike of chrono with the boundary of the year.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{HeavenlyStem, YearBoundary};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+    /// let stem = HeavenlyStem::from_datelike_with_boundary(&date, YearBoundary::Risshun);
+    ///
+    /// assert_eq!(HeavenlyStem::Mizunoto, stem);
+    /// ```
+    pub fn from_datelike_with_boundary<T: Datelike>(date: &T, boundary: YearBoundary) -> Self {
         // @refs https://spicomi.net/media/articles/1360
-        let index = match (date.year() + 7) % 10 {
+        let index = match (year_of(date, boundary) + 7) % 10 {
             0 => 10,
             n => n.abs(),
         };
@@ -112,6 +275,19 @@ impl HeavenlyStem {
         HEAVENLY_STEMS[self.to_number() - 1]
     }
 
+    /// Returns the reading of heavenly stem in hiragana.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::HeavenlyStem;
+    ///
+    /// assert_eq!("きのえ", HeavenlyStem::Kinoe.reading());
+    /// ```
+    pub const fn reading(&self) -> &'static str {
+        HEAVENLY_STEM_READINGS[self.to_number() - 1]
+    }
+
     /// Returns the number of heavenly stem.
     ///
     /// # Example
@@ -135,6 +311,23 @@ impl HeavenlyStem {
             Mizunoto => 10,
         }
     }
+
+    /// Returns the `YinYang` of heavenly stem.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::{HeavenlyStem, YinYang};
+    ///
+    /// assert_eq!(YinYang::Yang, HeavenlyStem::Kinoe.yin_yang());
+    /// assert_eq!(YinYang::Yin, HeavenlyStem::Kinoto.yin_yang());
+    /// ```
+    pub const fn yin_yang(&self) -> YinYang {
+        match self.to_number() % 2 {
+            1 => Yang,
+            _ => Yin,
+        }
+    }
 }
 
 /// Japanese zodiac
@@ -172,6 +365,78 @@ pub enum JapaneseZodiac {
 }
 
 impl JapaneseZodiac {
+    /// Returns the name of the animal in English.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::JapaneseZodiac;
+    ///
+    /// assert_eq!("Dragon", JapaneseZodiac::Tatsu.animal_english_name());
+    /// ```
+    pub const fn animal_english_name(&self) -> &'static str {
+        JAPANESE_ZODIAC_ANIMALS[self.number() - 1].1
+    }
+
+    /// Returns the name of the animal in Japanese.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::JapaneseZodiac;
+    ///
+    /// assert_eq!("竜", JapaneseZodiac::Tatsu.animal_name());
+    /// ```
+    pub const fn animal_name(&self) -> &'static str {
+        JAPANESE_ZODIAC_ANIMALS[self.number() - 1].0
+    }
+
+    /// Returns the azimuth in degrees clockwise from north (子).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::JapaneseZodiac;
+    ///
+    /// assert_eq!(90, JapaneseZodiac::Wu.azimuth());
+    /// ```
+    pub const fn azimuth(&self) -> u32 {
+        (self.number() as u32 - 1) * 30
+    }
+
+    /// Returns the name of direction.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::JapaneseZodiac;
+    ///
+    /// assert_eq!("東北東", JapaneseZodiac::Tora.direction());
+    /// ```
+    pub const fn direction(&self) -> &'static str {
+        JAPANESE_ZODIAC_DIRECTIONS[self.number() - 1]
+    }
+
+    /// Returns the `FiveElement` of Japanese zodiac.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::{FiveElement, JapaneseZodiac};
+    ///
+    /// assert_eq!(FiveElement::Water, JapaneseZodiac::Ne.element());
+    /// assert_eq!(FiveElement::Earth, JapaneseZodiac::Tatsu.element());
+    /// ```
+    pub const fn element(&self) -> FiveElement {
+        match self {
+            Tora | Wu => Wood,
+            Mi | Uma => Fire,
+            Ushi | Tatsu | Hitsuji | Inu => Earth,
+            Saru | Tori => Metal,
+            Yi | Ne => Water,
+        }
+    }
+
     /// Generate from Datelike of chrono.
     ///
     /// # Example
@@ -184,8 +449,25 @@ impl JapaneseZodiac {
     /// assert_eq!(JapaneseZodiac::Tatsu, JapaneseZodiac::from_datelike(&date));
     /// ```
     pub fn from_datelike<T: Datelike>(date: &T) -> Self {
+        JapaneseZodiac::from_datelike_with_boundary(date, NewYearsDay)
+    }
+
+    /// Generate from Datelike of chrono with the boundary of the year.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{JapaneseZodiac, YearBoundary};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+    /// let zodiac = JapaneseZodiac::from_datelike_with_boundary(&date, YearBoundary::Risshun);
+    ///
+    /// assert_eq!(JapaneseZodiac::Wu, zodiac);
+    /// ```
+    pub fn from_datelike_with_boundary<T: Datelike>(date: &T, boundary: YearBoundary) -> Self {
         // @refs https://spicomi.net/media/articles/1360
-        let index = match (date.year() + 9) % 12 {
+        let index = match (year_of(date, boundary) + 9) % 12 {
             0 => 12,
             n => n.abs(),
         };
@@ -237,6 +519,38 @@ impl JapaneseZodiac {
         }
     }
 
+    /// Generate from the traditional hour (十二時辰) of Timelike of chrono.
+    ///
+    /// Each hour lasts for two hours, such as 子の刻 from 23:00 until 1:00.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveTime;
+    /// use koyomi_rs::JapaneseZodiac;
+    ///
+    /// let time = NaiveTime::from_hms_opt(12, 30, 0).unwrap();
+    /// assert_eq!(JapaneseZodiac::Uma, JapaneseZodiac::from_timelike(&time));
+    /// ```
+    pub fn from_timelike<T: Timelike>(time: &T) -> Self {
+        JapaneseZodiac::from_number((time.hour() as usize).div_ceil(2) % 12 + 1).unwrap()
+    }
+
+    /// Returns the traditional hour (十二時辰) as the start and end hours.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::JapaneseZodiac;
+    ///
+    /// assert_eq!((23, 1), JapaneseZodiac::Ne.hours());
+    /// assert_eq!((11, 13), JapaneseZodiac::Uma.hours());
+    /// ```
+    pub const fn hours(&self) -> (u32, u32) {
+        let start = (self.number() as u32 * 2 + 21) % 24;
+        (start, (start + 2) % 24)
+    }
+
     /// Returns the name of Japanese zodiac.
     ///
     /// # Example
@@ -276,6 +590,36 @@ impl JapaneseZodiac {
             Yi => 12,
         }
     }
+
+    /// Returns the reading of Japanese zodiac in hiragana.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::JapaneseZodiac;
+    ///
+    /// assert_eq!("ね", JapaneseZodiac::Ne.reading());
+    /// ```
+    pub const fn reading(&self) -> &'static str {
+        JAPANESE_ZODIAC_READINGS[self.number() - 1]
+    }
+
+    /// Returns the `YinYang` of Japanese zodiac.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::{JapaneseZodiac, YinYang};
+    ///
+    /// assert_eq!(YinYang::Yang, JapaneseZodiac::Ne.yin_yang());
+    /// assert_eq!(YinYang::Yin, JapaneseZodiac::Ushi.yin_yang());
+    /// ```
+    pub const fn yin_yang(&self) -> YinYang {
+        match self.number() % 2 {
+            1 => Yang,
+            _ => Yin,
+        }
+    }
 }
 
 /// Sexagenary cycle
@@ -421,8 +765,31 @@ impl SexagenaryCycle {
     /// assert_eq!(SexagenaryCycle::KinoeTatsu, SexagenaryCycle::from_datelike(&date));
     /// ```
     pub fn from_datelike<T: Datelike>(date: &T) -> Self {
-        let hs = HeavenlyStem::from_datelike(date);
-        let jz = JapaneseZodiac::from_datelike(date);
+        SexagenaryCycle::from_datelike_with_boundary(date, NewYearsDay)
+    }
+
+    /// Generate from Datelike of chrono with the boundary of the year.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::{SexagenaryCycle, YearBoundary};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+    ///
+    /// assert_eq!(
+    ///     SexagenaryCycle::KinoeTatsu,
+    ///     SexagenaryCycle::from_datelike_with_boundary(&date, YearBoundary::NewYearsDay)
+    /// );
+    /// assert_eq!(
+    ///     SexagenaryCycle::MizunotoWu,
+    ///     SexagenaryCycle::from_datelike_with_boundary(&date, YearBoundary::Risshun)
+    /// );
+    /// ```
+    pub fn from_datelike_with_boundary<T: Datelike>(date: &T, boundary: YearBoundary) -> Self {
+        let hs = HeavenlyStem::from_datelike_with_boundary(date, boundary);
+        let jz = JapaneseZodiac::from_datelike_with_boundary(date, boundary);
         let sc = format!("{}{}", hs.name(), jz.name());
 
         SexagenaryCycle::from_name(&sc).unwrap()
@@ -444,6 +811,32 @@ impl SexagenaryCycle {
             .and_then(|i| SexagenaryCycle::from_number(i + 1))
     }
 
+    /// Returns the `HeavenlyStem` of sexagenary cycle.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::{HeavenlyStem, SexagenaryCycle};
+    ///
+    /// assert_eq!(HeavenlyStem::Kinoe, SexagenaryCycle::KinoeTatsu.heavenly_stem());
+    /// ```
+    pub const fn heavenly_stem(&self) -> HeavenlyStem {
+        HeavenlyStem::from_number((self.number() - 1) % 10 + 1).unwrap()
+    }
+
+    /// Returns the `Nacchin` of sexagenary cycle.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::SexagenaryCycle;
+    ///
+    /// assert_eq!("覆燈火", SexagenaryCycle::KinoeTatsu.nacchin().name());
+    /// ```
+    pub const fn nacchin(&self) -> Nacchin {
+        Nacchin::from_number(self.number().div_ceil(2)).unwrap()
+    }
+
     /// Returns the name of sexagenary cycle.
     ///
     /// # Example
@@ -457,7 +850,75 @@ impl SexagenaryCycle {
         SEXAGENARY_CYCLE[self.number() - 1]
     }
 
-    const fn from_number(number: usize) -> Option<Self> {
+    /// Returns the sexagenary cycle of the day (日の干支).
+    ///
+    /// The cycle of days has continued without a break regardless of the calendar.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::SexagenaryCycle;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// assert_eq!(SexagenaryCycle::KinoeNe, SexagenaryCycle::of_day(&date));
+    /// ```
+    pub fn of_day<T: Datelike>(date: &T) -> Self {
+        SexagenaryCycle::from_number(day_cycle_index(date) + 1).unwrap()
+    }
+
+    /// Returns the sexagenary cycle of the month (月の干支).
+    ///
+    /// The month changes at the beginning of each 節 (節入り) of the solar terms,
+    /// such as 立春 for 寅 month and 啓蟄 for 卯 month, instead of the first day of the month.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::SexagenaryCycle;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 2, 3).unwrap();
+    /// assert_eq!(SexagenaryCycle::KinotoUshi, SexagenaryCycle::of_month(&date));
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 2, 4).unwrap(); // 立春
+    /// assert_eq!(SexagenaryCycle::HinoeTora, SexagenaryCycle::of_month(&date));
+    /// ```
+    pub fn of_month<T: Datelike>(date: &T) -> Self {
+        let (year, month) = solar_month(date);
+        // 寅 month of 甲 year (such as 2024) is 丙寅.
+        let index = (year as i64 * 12 + month as i64 + 14).rem_euclid(60);
+
+        SexagenaryCycle::from_number(index as usize + 1).unwrap()
+    }
+
+    /// Returns the reading of sexagenary cycle in hiragana.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::SexagenaryCycle;
+    ///
+    /// assert_eq!("きのえたつ", SexagenaryCycle::KinoeTatsu.reading());
+    /// ```
+    pub const fn reading(&self) -> &'static str {
+        SEXAGENARY_CYCLE_READINGS[self.number() - 1]
+    }
+
+    /// Returns the `JapaneseZodiac` of sexagenary cycle.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::{JapaneseZodiac, SexagenaryCycle};
+    ///
+    /// assert_eq!(JapaneseZodiac::Tatsu, SexagenaryCycle::KinoeTatsu.zodiac());
+    /// ```
+    pub const fn zodiac(&self) -> JapaneseZodiac {
+        JapaneseZodiac::from_number((self.number() - 1) % 12 + 1).unwrap()
+    }
+
+    pub(crate) const fn from_number(number: usize) -> Option<Self> {
         match number {
             1 => Some(KinoeNe),
             2 => Some(KinotoUshi),
@@ -589,10 +1050,239 @@ impl SexagenaryCycle {
     }
 }
 
+/// Nine stars (九星)
+///
+/// It derives the nine stars of 九星気学 for the year, the month and the day.
+/// The year changes at 立春 and the month changes at each 節 of the solar terms.
+///
+/// You can convert between the order of the nine stars,
+/// noting that it starts from `1` (一白水星).
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum NineStar {
+    /// 一白水星
+    IppakuSuisei,
+    /// 二黒土星
+    JikokuDosei,
+    /// 三碧木星
+    SanpekiMokusei,
+    /// 四緑木星
+    ShirokuMokusei,
+    /// 五黄土星
+    GoouDosei,
+    /// 六白金星
+    RoppakuKinsei,
+    /// 七赤金星
+    ShichisekiKinsei,
+    /// 八白土星
+    HappakuDosei,
+    /// 九紫火星
+    KyushiKasei,
+}
+
+impl NineStar {
+    /// Generate from Datelike of chrono.
+    ///
+    /// Returns the star of the year, which changes at 立春.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::NineStar;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 2, 3).unwrap();
+    /// assert_eq!(NineStar::ShirokuMokusei, NineStar::from_datelike(&date));
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 2, 4).unwrap(); // 立春
+    /// assert_eq!(NineStar::SanpekiMokusei, NineStar::from_datelike(&date));
+    /// ```
+    pub fn from_datelike<T: Datelike>(date: &T) -> Self {
+        let (year, _) = solar_month(date);
+        // The star goes backward every year, such as 九紫火星 in 2018.
+        let index = (1 - year as i64).rem_euclid(9);
+
+        NineStar::from_number(index as usize + 1).unwrap()
+    }
+
+    /// Generate from name of nine star.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::NineStar;
+    ///
+    /// assert_eq!(Some(NineStar::GoouDosei), NineStar::from_name("五黄土星"));
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        NINE_STARS
+            .iter()
+            .position(|&(x, _)| x == name)
+            .and_then(|i| NineStar::from_number(i + 1))
+    }
+
+    /// Generate from number of nine star.
+    /// The index starts from `1` (一白水星).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::NineStar;
+    ///
+    /// assert!(NineStar::from_number(9).is_some());
+    /// assert!(NineStar::from_number(10).is_none());
+    /// ```
+    pub const fn from_number(number: usize) -> Option<Self> {
+        match number {
+            1 => Some(IppakuSuisei),
+            2 => Some(JikokuDosei),
+            3 => Some(SanpekiMokusei),
+            4 => Some(ShirokuMokusei),
+            5 => Some(GoouDosei),
+            6 => Some(RoppakuKinsei),
+            7 => Some(ShichisekiKinsei),
+            8 => Some(HappakuDosei),
+            9 => Some(KyushiKasei),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of nine star.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::NineStar;
+    ///
+    /// assert_eq!("一白水星", NineStar::IppakuSuisei.name());
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        NINE_STARS[self.to_number() - 1].0
+    }
+
+    /// Returns the star of the day (日家九星).
+    ///
+    /// The stars go forward from 一白水星 (陽遁) after the 甲子 day nearest to 冬至,
+    /// and go backward from 九紫火星 (陰遁) after the 甲子 day nearest to 夏至.
+    ///
+    /// Returns `None` if there is no 甲子 day nearest to the solstice before the date,
+    /// such as [`NaiveDate::MIN`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::NineStar;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// assert_eq!(Some(NineStar::IppakuSuisei), NineStar::of_day(&date));
+    /// ```
+    pub fn of_day<T: Datelike>(date: &T) -> Option<Self> {
+        let date = to_naive_date(date);
+        let (start, ascending) = [date.year() - 1, date.year()]
+            .into_iter()
+            .flat_map(|y| [(y, SolarTerm::Geshi, false), (y, SolarTerm::Touji, true)])
+            .filter_map(|(y, term, ascending)| {
+                let solstice = term.datetime(y)?.date();
+                let index = day_cycle_index(&solstice) as u64;
+                let kinoe_ne = if index <= 30 {
+                    solstice.checked_sub_days(Days::new(index))
+                } else {
+                    solstice.checked_add_days(Days::new(60 - index))
+                };
+                kinoe_ne.map(|d| (d, ascending))
+            })
+            .filter(|(d, _)| *d <= date)
+            .max()?;
+        let days = (date - start).num_days() as usize % 9;
+
+        let number = if ascending { days + 1 } else { 9 - days };
+        NineStar::from_number(number)
+    }
+
+    /// Returns the star of the month (月命星).
+    ///
+    /// The month changes at each 節 of the solar terms, the same as the sexagenary cycle of the month.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::NineStar;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 2, 4).unwrap(); // 立春
+    /// assert_eq!(NineStar::GoouDosei, NineStar::of_month(&date));
+    /// ```
+    pub fn of_month<T: Datelike>(date: &T) -> Self {
+        let (year, month) = solar_month(date);
+        // 寅 month is 八白土星 in 子, 卯, 午 and 酉 years, 五黄土星 in 丑, 辰, 未 and 戌 years,
+        // and 二黒土星 in 寅, 巳, 申 and 亥 years.
+        let first = 7 - 3 * ((year as i64 + 8).rem_euclid(12) % 3);
+        let index = (first - month as i64).rem_euclid(9);
+
+        NineStar::from_number(index as usize + 1).unwrap()
+    }
+
+    /// Returns the reading of nine star in hiragana.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::NineStar;
+    ///
+    /// assert_eq!("きゅうしかせい", NineStar::KyushiKasei.reading());
+    /// ```
+    pub const fn reading(&self) -> &'static str {
+        NINE_STARS[self.to_number() - 1].1
+    }
+
+    /// Returns the number of nine star.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::NineStar;
+    ///
+    /// assert_eq!(9, NineStar::KyushiKasei.to_number());
+    /// ```
+    pub const fn to_number(&self) -> usize {
+        match self {
+            IppakuSuisei => 1,
+            JikokuDosei => 2,
+            SanpekiMokusei => 3,
+            ShirokuMokusei => 4,
+            GoouDosei => 5,
+            RoppakuKinsei => 6,
+            ShichisekiKinsei => 7,
+            HappakuDosei => 8,
+            KyushiKasei => 9,
+        }
+    }
+}
+
+/// Returns the year which begins at 立春 and the month counted from `0` (寅 month)
+/// which begins at each 節 of the solar terms.
+pub(crate) fn solar_month<T: Datelike>(date: &T) -> (i32, usize) {
+    // Each month consists of a 節 and a 中気, starting from 立春.
+    let month = (SolarTerm::period_of(date).to_number() - 1) / 2;
+    let year = match (date.month(), month) {
+        (1 | 2, 10..) => date.year() - 1,
+        _ => date.year(),
+    };
+
+    (year, month)
+}
+
+fn year_of<T: Datelike>(date: &T, boundary: YearBoundary) -> i32 {
+    match boundary {
+        NewYearsDay => date.year(),
+        Risshun => solar_month(date).0,
+    }
+}
+
 #[cfg(test)]
 mod tests_heavenly_stem {
-    use super::HeavenlyStem;
     use super::HeavenlyStem::*;
+    use super::{FiveElement, HeavenlyStem, YearBoundary, YinYang};
 
     use chrono::NaiveDate;
     use rstest::rstest;
@@ -603,6 +1293,20 @@ mod tests_heavenly_stem {
         assert_eq!(HeavenlyStem::Kinoe, HeavenlyStem::from_datelike(&date));
     }
 
+    #[rstest]
+    #[case((2024, 2, 3), Mizunoto)]
+    #[case((2024, 2, 4), Kinoe)]
+    fn 立春を年の境界として変換できる(
+        #[case] date: (i32, u32, u32),
+        #[case] expect: HeavenlyStem,
+    ) {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+        assert_eq!(
+            expect,
+            HeavenlyStem::from_datelike_with_boundary(&date, YearBoundary::Risshun)
+        );
+    }
+
     #[rstest]
     #[case("甲", Kinoe)]
     #[case("乙", Kinoto)]
@@ -615,7 +1319,25 @@ mod tests_heavenly_stem {
     #[case("壬", Mizunoe)]
     #[case("癸", Mizunoto)]
     fn 十干の名前から変換できる(#[case] name: &str, #[case] expect: HeavenlyStem) {
-        assert_eq!(Some(expect), HeavenlyStem::from_name(&name));
+        assert_eq!(Some(expect), HeavenlyStem::from_name(name));
+    }
+
+    #[rstest]
+    #[case(Kinoe, "きのえ", FiveElement::Wood, YinYang::Yang)]
+    #[case(Kinoto, "きのと", FiveElement::Wood, YinYang::Yin)]
+    #[case(Hinoe, "ひのえ", FiveElement::Fire, YinYang::Yang)]
+    #[case(Tsuchinoto, "つちのと", FiveElement::Earth, YinYang::Yin)]
+    #[case(Kanoe, "かのえ", FiveElement::Metal, YinYang::Yang)]
+    #[case(Mizunoto, "みずのと", FiveElement::Water, YinYang::Yin)]
+    fn 十干の読みと五行と陰陽を導出できる(
+        #[case] stem: HeavenlyStem,
+        #[case] reading: &str,
+        #[case] element: FiveElement,
+        #[case] yin_yang: YinYang,
+    ) {
+        assert_eq!(reading, stem.reading());
+        assert_eq!(element, stem.element());
+        assert_eq!(yin_yang, stem.yin_yang());
     }
 
     #[rstest]
@@ -676,10 +1398,10 @@ mod tests_heavenly_stem {
 
 #[cfg(test)]
 mod tests_japanese_zodiac {
-    use super::JapaneseZodiac;
     use super::JapaneseZodiac::*;
+    use super::{FiveElement, JapaneseZodiac, YearBoundary, YinYang};
 
-    use chrono::NaiveDate;
+    use chrono::{NaiveDate, NaiveTime};
     use rstest::rstest;
 
     #[rstest]
@@ -700,6 +1422,72 @@ mod tests_japanese_zodiac {
         assert_eq!(expect, JapaneseZodiac::from_datelike(&date));
     }
 
+    #[rstest]
+    #[case((2024, 2, 1), Wu)]
+    #[case((2024, 2, 4), Tatsu)]
+    #[case((2025, 2, 2), Tatsu)]
+    fn 立春を年の境界として十二支を導出できる(
+        #[case] date: (i32, u32, u32),
+        #[case] expect: JapaneseZodiac,
+    ) {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+        assert_eq!(
+            expect,
+            JapaneseZodiac::from_datelike_with_boundary(&date, YearBoundary::Risshun)
+        );
+    }
+
+    #[rstest]
+    #[case(Ne, "ね", "鼠", "Rat", FiveElement::Water, YinYang::Yang)]
+    #[case(Ushi, "うし", "牛", "Ox", FiveElement::Earth, YinYang::Yin)]
+    #[case(Wu, "う", "兎", "Rabbit", FiveElement::Wood, YinYang::Yin)]
+    #[case(Uma, "うま", "馬", "Horse", FiveElement::Fire, YinYang::Yang)]
+    #[case(Tori, "とり", "鶏", "Rooster", FiveElement::Metal, YinYang::Yin)]
+    #[case(Yi, "い", "猪", "Boar", FiveElement::Water, YinYang::Yin)]
+    fn 十二支の読みと動物と五行と陰陽を導出できる(
+        #[case] zodiac: JapaneseZodiac,
+        #[case] reading: &str,
+        #[case] animal: &str,
+        #[case] english: &str,
+        #[case] element: FiveElement,
+        #[case] yin_yang: YinYang,
+    ) {
+        assert_eq!(reading, zodiac.reading());
+        assert_eq!(animal, zodiac.animal_name());
+        assert_eq!(english, zodiac.animal_english_name());
+        assert_eq!(element, zodiac.element());
+        assert_eq!(yin_yang, zodiac.yin_yang());
+    }
+
+    #[rstest]
+    #[case(Ne, "北", 0, (23, 1))]
+    #[case(Wu, "東", 90, (5, 7))]
+    #[case(Uma, "南", 180, (11, 13))]
+    #[case(Tori, "西", 270, (17, 19))]
+    #[case(Yi, "北北西", 330, (21, 23))]
+    fn 十二支の方角と時刻を導出できる(
+        #[case] zodiac: JapaneseZodiac,
+        #[case] direction: &str,
+        #[case] azimuth: u32,
+        #[case] hours: (u32, u32),
+    ) {
+        assert_eq!(direction, zodiac.direction());
+        assert_eq!(azimuth, zodiac.azimuth());
+        assert_eq!(hours, zodiac.hours());
+    }
+
+    #[rstest]
+    #[case(0, Ne)]
+    #[case(1, Ushi)]
+    #[case(2, Ushi)]
+    #[case(12, Uma)]
+    #[case(22, Yi)]
+    #[case(23, Ne)]
+    fn 時刻から十二支を導出できる(#[case] hour: u32, #[case] expect: JapaneseZodiac) {
+        let time = NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+        assert_eq!(expect, JapaneseZodiac::from_timelike(&time));
+    }
+
     #[rstest]
     #[case("子", Ne)]
     #[case("丑", Ushi)]
@@ -784,8 +1572,8 @@ mod tests_japanese_zodiac {
 
 #[cfg(test)]
 mod tests_sexagenary_cycle {
-    use super::SexagenaryCycle;
     use super::SexagenaryCycle::*;
+    use super::{SexagenaryCycle, YearBoundary};
 
     use chrono::NaiveDate;
     use rstest::rstest;
@@ -799,6 +1587,85 @@ mod tests_sexagenary_cycle {
         );
     }
 
+    #[rstest]
+    #[case(KinoeNe, "きのえね")]
+    #[case(KinoeTatsu, "きのえたつ")]
+    #[case(HinoeUma, "ひのえうま")]
+    #[case(MizunotoYi, "みずのとい")]
+    fn 干支の読みを導出できる(#[case] cycle: SexagenaryCycle, #[case] expect: &str) {
+        assert_eq!(expect, cycle.reading());
+    }
+
+    #[rstest]
+    fn 干支を十干と十二支に分解できる() {
+        for number in 1..=60 {
+            let cycle = SexagenaryCycle::from_number(number).unwrap();
+            let name = format!("{}{}", cycle.heavenly_stem().name(), cycle.zodiac().name());
+
+            assert_eq!(cycle.name(), name);
+        }
+    }
+
+    #[rstest]
+    #[case((2024, 1, 1), KinoeTatsu, MizunotoWu)]
+    #[case((2024, 2, 1), KinoeTatsu, MizunotoWu)]
+    #[case((2024, 2, 3), KinoeTatsu, MizunotoWu)]
+    #[case((2024, 2, 4), KinoeTatsu, KinoeTatsu)]
+    #[case((2024, 12, 31), KinoeTatsu, KinoeTatsu)]
+    #[case((2025, 2, 2), KinotoMi, KinoeTatsu)]
+    #[case((2025, 2, 3), KinotoMi, KinotoMi)]
+    fn 年の境界を指定して変換できる(
+        #[case] date: (i32, u32, u32),
+        #[case] new_years_day: SexagenaryCycle,
+        #[case] risshun: SexagenaryCycle,
+    ) {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+
+        assert_eq!(
+            new_years_day,
+            SexagenaryCycle::from_datelike_with_boundary(&date, YearBoundary::NewYearsDay)
+        );
+        assert_eq!(
+            risshun,
+            SexagenaryCycle::from_datelike_with_boundary(&date, YearBoundary::Risshun)
+        );
+    }
+
+    #[rstest]
+    #[case((2024, 1, 1), KinoeNe)]
+    #[case((2024, 1, 2), KinotoUshi)]
+    #[case((2024, 2, 29), MizunotoYi)]
+    #[case((2024, 10, 17), KinoeTora)]
+    #[case((2023, 12, 31), MizunotoYi)]
+    #[case((1900, 1, 1), KinoeInu)]
+    fn 日の干支を導出できる(
+        #[case] date: (i32, u32, u32),
+        #[case] expect: SexagenaryCycle,
+    ) {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+
+        assert_eq!(expect, SexagenaryCycle::of_day(&date));
+    }
+
+    #[rstest]
+    #[case((2024, 1, 5), KinoeNe)]
+    #[case((2024, 1, 6), KinotoUshi)]
+    #[case((2024, 2, 3), KinotoUshi)]
+    #[case((2024, 2, 4), HinoeTora)]
+    #[case((2024, 3, 5), HinotoWu)]
+    #[case((2024, 10, 17), KinoeInu)]
+    #[case((2024, 12, 7), HinoeNe)]
+    #[case((2025, 1, 5), HinotoUshi)]
+    #[case((2025, 2, 3), TsuchinoeTora)]
+    fn 節入りを境に月の干支を導出できる(
+        #[case] date: (i32, u32, u32),
+        #[case] expect: SexagenaryCycle,
+    ) {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+
+        assert_eq!(expect, SexagenaryCycle::of_month(&date));
+    }
+
     #[rstest]
     #[case("甲子", KinoeNe)]
     #[case("乙丑", KinotoUshi)]
@@ -864,7 +1731,7 @@ mod tests_sexagenary_cycle {
         #[case] name: &str,
         #[case] expect: SexagenaryCycle,
     ) {
-        assert_eq!(Some(expect), SexagenaryCycle::from_name(&name));
+        assert_eq!(Some(expect), SexagenaryCycle::from_name(name));
     }
 
     #[rstest]
@@ -940,3 +1807,82 @@ mod tests_sexagenary_cycle {
         assert_eq!(expect, cycle.name());
     }
 }
+
+#[cfg(test)]
+mod tests_nine_star {
+    use super::NineStar;
+    use super::NineStar::*;
+
+    use chrono::NaiveDate;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case((2018, 6, 1), KyushiKasei)]
+    #[case((2023, 6, 1), ShirokuMokusei)]
+    #[case((2024, 2, 3), ShirokuMokusei)]
+    #[case((2024, 2, 4), SanpekiMokusei)]
+    #[case((2025, 1, 1), SanpekiMokusei)]
+    #[case((2025, 2, 2), SanpekiMokusei)]
+    #[case((2025, 2, 3), JikokuDosei)]
+    #[case((2026, 6, 1), IppakuSuisei)]
+    fn 年の九星を導出できる(#[case] date: (i32, u32, u32), #[case] expect: NineStar) {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+        assert_eq!(expect, NineStar::from_datelike(&date));
+    }
+
+    #[rstest]
+    #[case((2024, 1, 1), ShichisekiKinsei)]
+    #[case((2024, 1, 6), RoppakuKinsei)]
+    #[case((2024, 2, 4), GoouDosei)]
+    #[case((2024, 3, 5), ShirokuMokusei)]
+    #[case((2024, 6, 20), IppakuSuisei)]
+    #[case((2024, 12, 31), ShirokuMokusei)]
+    #[case((2025, 2, 2), SanpekiMokusei)]
+    #[case((2025, 2, 3), JikokuDosei)]
+    #[case((2026, 2, 4), HappakuDosei)]
+    fn 月の九星を導出できる(#[case] date: (i32, u32, u32), #[case] expect: NineStar) {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+        assert_eq!(expect, NineStar::of_month(&date));
+    }
+
+    #[rstest]
+    #[case((2024, 1, 1), IppakuSuisei)]
+    #[case((2024, 1, 2), JikokuDosei)]
+    #[case((2024, 1, 10), IppakuSuisei)]
+    #[case((2024, 6, 28), KyushiKasei)]
+    #[case((2024, 6, 29), KyushiKasei)]
+    #[case((2024, 6, 30), HappakuDosei)]
+    #[case((2024, 12, 25), IppakuSuisei)]
+    #[case((2024, 12, 26), IppakuSuisei)]
+    #[case((2024, 12, 27), JikokuDosei)]
+    fn 日の九星を陽遁と陰遁で導出できる(
+        #[case] date: (i32, u32, u32),
+        #[case] expect: NineStar,
+    ) {
+        let date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+        assert_eq!(Some(expect), NineStar::of_day(&date));
+    }
+
+    #[rstest]
+    #[case(NaiveDate::MIN, None)]
+    #[case(NaiveDate::MAX, Some(IppakuSuisei))]
+    fn 日の九星を範囲の端でも導出できる(
+        #[case] date: NaiveDate,
+        #[case] expect: Option<NineStar>,
+    ) {
+        assert_eq!(expect, NineStar::of_day(&date));
+    }
+
+    #[rstest]
+    fn 名前と読みと番号を相互に変換できる() {
+        for number in 1..=9 {
+            let star = NineStar::from_number(number).unwrap();
+
+            assert_eq!(number, star.to_number());
+            assert_eq!(Some(star), NineStar::from_name(star.name()));
+            assert!(!star.reading().is_empty());
+        }
+        assert!(NineStar::from_number(0).is_none());
+        assert!(NineStar::from_name("十白").is_none());
+    }
+}
@@ -0,0 +1,312 @@
+use crate::astronomy::{jde_from_date, solar_longitude};
+use crate::day::JapaneseHoliday;
+use crate::internal::{day_cycle_index, to_naive_date};
+use crate::solar_term::SolarTerm;
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime};
+
+use self::Zassetsu::*;
+
+/// Seasonal festival days (雑節)
+///
+/// Seasonal days of the traditional Japanese almanac other than the solar terms,
+/// derived in the same way as 暦要項 of the National Astronomical Observatory of Japan.
+///
+/// The variants are declared in the order in which they appear within a year.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Zassetsu {
+    /// 冬の土用入り
+    FuyuDoyoIri,
+    /// 冬の土用の丑の日
+    FuyuDoyoUshi,
+    /// 節分
+    Setsubun,
+    /// 春の彼岸入り
+    HaruHiganIri,
+    /// 春の社日
+    HaruShanichi,
+    /// 春の彼岸明け
+    HaruHiganAke,
+    /// 春の土用入り
+    HaruDoyoIri,
+    /// 春の土用の丑の日
+    HaruDoyoUshi,
+    /// 八十八夜
+    Hachijuhachiya,
+    /// 入梅
+    Nyubai,
+    /// 半夏生
+    Hangesho,
+    /// 夏の土用入り
+    NatsuDoyoIri,
+    /// 夏の土用の丑の日
+    NatsuDoyoUshi,
+    /// 二百十日
+    Nihyakutoka,
+    /// 二百二十日
+    Nihyakuhatsuka,
+    /// 秋の彼岸入り
+    AkiHiganIri,
+    /// 秋の社日
+    AkiShanichi,
+    /// 秋の彼岸明け
+    AkiHiganAke,
+    /// 秋の土用入り
+    AkiDoyoIri,
+    /// 秋の土用の丑の日
+    AkiDoyoUshi,
+}
+
+impl Zassetsu {
+    /// Returns the seasonal festival days of the specified year in date order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::Zassetsu;
+    ///
+    /// let days = Zassetsu::dates_in_year(2024);
+    ///
+    /// assert_eq!(
+    ///     (NaiveDate::from_ymd_opt(2024, 1, 18).unwrap(), Zassetsu::FuyuDoyoIri),
+    ///     days[0]
+    /// );
+    /// ```
+    pub fn dates_in_year(year: i32) -> Vec<(NaiveDate, Self)> {
+        let Some(first) = NaiveDate::from_ymd_opt(year, 1, 1) else {
+            return Vec::new();
+        };
+
+        first
+            .iter_days()
+            .take_while(|d| d.year() == year)
+            .flat_map(|d| Zassetsu::from_datelike(&d).into_iter().map(move |z| (d, z)))
+            .collect()
+    }
+
+    /// Generate from Datelike of chrono.
+    ///
+    /// Returns all of the seasonal festival days on the date,
+    /// since some of them such as 土用入り and 土用の丑の日 can fall on the same date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi_rs::Zassetsu;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 2, 3).unwrap();
+    /// assert_eq!(vec![Zassetsu::Setsubun], Zassetsu::from_datelike(&date));
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 2, 4).unwrap();
+    /// assert!(Zassetsu::from_datelike(&date).is_empty());
+    /// ```
+    pub fn from_datelike<T: Datelike>(date: &T) -> Vec<Self> {
+        let date = to_naive_date(date);
+        let jde = jde_from_date(&date);
+        let (start, end) = (solar_longitude(jde), solar_longitude(jde + 1.0));
+        let begins = |longitude: f64| {
+            let passed = (longitude - start).rem_euclid(360.0);
+            0.0 < passed && passed <= (end - start).rem_euclid(360.0)
+        };
+
+        let mut days = Vec::new();
+
+        match date.month() {
+            2 if risshun_after(&date, 1) => days.push(Setsubun),
+            3 => days.extend(higan(
+                &date,
+                JapaneseHoliday::vernal_equinox(date.year()),
+                (HaruHiganIri, HaruShanichi, HaruHiganAke),
+            )),
+            4 | 5 if risshun_before(&date, 87) => days.push(Hachijuhachiya),
+            8 | 9 if risshun_before(&date, 209) => days.push(Nihyakutoka),
+            9 if risshun_before(&date, 219) => days.push(Nihyakuhatsuka),
+            _ => {}
+        }
+        if date.month() == 9 {
+            days.extend(higan(
+                &date,
+                JapaneseHoliday::autumnal_equinox(date.year()),
+                (AkiHiganIri, AkiShanichi, AkiHiganAke),
+            ));
+        }
+
+        if begins(80.0) {
+            days.push(Nyubai);
+        }
+        if begins(100.0) {
+            days.push(Hangesho);
+        }
+
+        // 土用 lasts from 18 days before each of 立春, 立夏, 立秋 and 立冬 until the day before.
+        let doyo = [
+            (297.0, FuyuDoyoIri, FuyuDoyoUshi),
+            (27.0, HaruDoyoIri, HaruDoyoUshi),
+            (117.0, NatsuDoyoIri, NatsuDoyoUshi),
+            (207.0, AkiDoyoIri, AkiDoyoUshi),
+        ];
+        for (longitude, iri, ushi) in doyo {
+            if begins(longitude) {
+                days.push(iri);
+            }
+            // 丑 is the 2nd of the earthly branches.
+            if (longitude..longitude + 18.0).contains(&end) && day_cycle_index(&date) % 12 == 1 {
+                days.push(ushi);
+            }
+        }
+
+        days.sort();
+        days
+    }
+
+    /// Returns the name of seasonal festival day.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koyomi_rs::Zassetsu;
+    ///
+    /// assert_eq!("八十八夜", Zassetsu::Hachijuhachiya.name());
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        match self {
+            AkiDoyoIri => "秋の土用入り",
+            AkiDoyoUshi => "秋の土用の丑の日",
+            AkiHiganAke => "秋の彼岸明け",
+            AkiHiganIri => "秋の彼岸入り",
+            AkiShanichi => "秋の社日",
+            FuyuDoyoIri => "冬の土用入り",
+            FuyuDoyoUshi => "冬の土用の丑の日",
+            Hachijuhachiya => "八十八夜",
+            Hangesho => "半夏生",
+            HaruDoyoIri => "春の土用入り",
+            HaruDoyoUshi => "春の土用の丑の日",
+            HaruHiganAke => "春の彼岸明け",
+            HaruHiganIri => "春の彼岸入り",
+            HaruShanichi => "春の社日",
+            NatsuDoyoIri => "夏の土用入り",
+            NatsuDoyoUshi => "夏の土用の丑の日",
+            Nihyakuhatsuka => "二百二十日",
+            Nihyakutoka => "二百十日",
+            Nyubai => "入梅",
+            Setsubun => "節分",
+        }
+    }
+}
+
+fn higan(
+    date: &NaiveDate,
+    equinox: Option<NaiveDateTime>,
+    (iri, shanichi, ake): (Zassetsu, Zassetsu, Zassetsu),
+) -> Vec<Zassetsu> {
+    let Some(equinox) = equinox else {
+        return Vec::new();
+    };
+    let diff = (*date - equinox.date()).num_days();
+
+    let mut days = Vec::new();
+    match diff {
+        -3 => days.push(iri),
+        3 => days.push(ake),
+        _ => {}
+    }
+    // 社日 is the 戊 day nearest to the equinox day.
+    // If the equinox day is 癸, the 戊 day before it is taken.
+    if day_cycle_index(date) % 10 == 4 && (-5..5).contains(&diff) {
+        days.push(shanichi);
+    }
+
+    days
+}
+
+fn risshun_after(date: &NaiveDate, days: u64) -> bool {
+    date.checked_add_days(Days::new(days))
+        .and_then(|d| SolarTerm::from_datelike(&d))
+        == Some(SolarTerm::Risshun)
+}
+
+fn risshun_before(date: &NaiveDate, days: u64) -> bool {
+    date.checked_sub_days(Days::new(days))
+        .and_then(|d| SolarTerm::from_datelike(&d))
+        == Some(SolarTerm::Risshun)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    fn 一年の雑節を日付順に列挙できる() {
+        let expect = vec![
+            ((1, 18), FuyuDoyoIri),
+            ((1, 26), FuyuDoyoUshi),
+            ((2, 3), Setsubun),
+            ((3, 15), HaruShanichi),
+            ((3, 17), HaruHiganIri),
+            ((3, 23), HaruHiganAke),
+            ((4, 16), HaruDoyoIri),
+            ((4, 19), HaruDoyoUshi),
+            ((5, 1), HaruDoyoUshi),
+            ((5, 1), Hachijuhachiya),
+            ((6, 10), Nyubai),
+            ((7, 1), Hangesho),
+            ((7, 19), NatsuDoyoIri),
+            ((7, 24), NatsuDoyoUshi),
+            ((8, 5), NatsuDoyoUshi),
+            ((8, 31), Nihyakutoka),
+            ((9, 10), Nihyakuhatsuka),
+            ((9, 19), AkiHiganIri),
+            ((9, 21), AkiShanichi),
+            ((9, 25), AkiHiganAke),
+            ((10, 20), AkiDoyoIri),
+            ((10, 28), AkiDoyoUshi),
+        ];
+        let expect: Vec<(NaiveDate, Zassetsu)> = expect
+            .into_iter()
+            .map(|((m, d), z)| (NaiveDate::from_ymd_opt(2024, m, d).unwrap(), z))
+            .collect();
+
+        assert_eq!(expect, Zassetsu::dates_in_year(2024));
+    }
+
+    #[rstest]
+    #[case((2025, 2, 2), Setsubun)]
+    #[case((2025, 3, 17), HaruHiganIri)]
+    #[case((2025, 5, 1), Hachijuhachiya)]
+    #[case((2025, 6, 11), Nyubai)]
+    #[case((2025, 7, 1), Hangesho)]
+    #[case((2025, 7, 19), NatsuDoyoUshi)]
+    #[case((2025, 8, 31), Nihyakutoka)]
+    #[case((2025, 9, 10), Nihyakuhatsuka)]
+    #[case((2025, 9, 20), AkiHiganIri)]
+    fn 年月日から変換できる(#[case] date: (i32, u32, u32), #[case] expect: Zassetsu) {
+        let (y, m, d) = date;
+        let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert!(Zassetsu::from_datelike(&date).contains(&expect));
+    }
+
+    #[rstest]
+    fn 土用入りと丑の日が重なる場合はどちらも返す() {
+        let date = NaiveDate::from_ymd_opt(2025, 7, 19).unwrap();
+
+        assert_eq!(
+            vec![NatsuDoyoIri, NatsuDoyoUshi],
+            Zassetsu::from_datelike(&date)
+        );
+    }
+
+    #[rstest]
+    #[case(2024, 1, 1)]
+    #[case(2024, 2, 4)]
+    #[case(2024, 3, 20)]
+    #[case(2024, 7, 25)]
+    fn 雑節でなければ空になる(#[case] y: i32, #[case] m: u32, #[case] d: u32) {
+        let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert!(Zassetsu::from_datelike(&date).is_empty());
+    }
+}
@@ -0,0 +1,22 @@
+extern crate chrono;
+extern crate koyomi_rs;
+
+use chrono::NaiveDate;
+use koyomi_rs::RegisterEraError;
+use koyomi_rs::prelude::*;
+
+#[test]
+fn 登録済みの元号と期間が重なる元号は登録できない() {
+    let start = NaiveDate::from_ymd_opt(2150, 1, 1).unwrap();
+    assert!(JapaneseEra::register("先元", "せんげん", "A", &start).is_ok());
+
+    for date in [start, NaiveDate::from_ymd_opt(2100, 1, 1).unwrap()] {
+        assert_eq!(
+            Err(RegisterEraError::OverlappingEra),
+            JapaneseEra::register("後元", "こうげん", "K", &date)
+        );
+    }
+
+    let next = NaiveDate::from_ymd_opt(2150, 1, 2).unwrap();
+    assert!(JapaneseEra::register("後元", "こうげん", "K", &next).is_ok());
+}
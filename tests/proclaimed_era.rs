@@ -0,0 +1,82 @@
+extern crate chrono;
+extern crate koyomi_rs;
+
+use chrono::NaiveDate;
+use koyomi_rs::RegisterEraError;
+use koyomi_rs::prelude::*;
+
+#[test]
+fn 新しい元号を登録すると和暦の導出に反映される() {
+    let start = NaiveDate::from_ymd_opt(2100, 4, 1).unwrap();
+    let era = JapaneseEra::register("新元", "しんげん", "n", &start).unwrap();
+
+    assert_eq!("新元", era.name());
+    assert_eq!("しんげん", era.reading());
+    assert_eq!("N", era.abbreviation());
+    assert_eq!(start, era.start_date());
+    assert!(era.end_date().is_none());
+
+    // Reiwa ends on the day before the new era.
+    let reiwa_end = NaiveDate::from_ymd_opt(2100, 3, 31).unwrap();
+    assert_eq!(Some(reiwa_end), JapaneseEra::Reiwa(1).end_date());
+    assert_eq!(
+        Some(JapaneseEra::Reiwa(82)),
+        JapaneseEra::from_datelike(&reiwa_end)
+    );
+    assert_eq!(Some(era), JapaneseEra::from_datelike(&start));
+
+    let date = NaiveDate::from_ymd_opt(2101, 1, 1).unwrap();
+    let japanese_date = JapaneseDate::from_datelike(&date);
+    assert_eq!(2, japanese_date.era().unwrap().year());
+    assert_eq!(Some("新元"), japanese_date.era_name());
+
+    let mut koyomi = Koyomi::between(&reiwa_end, &start);
+    assert_eq!(Some("令和"), koyomi.next().unwrap().era_name());
+    assert_eq!(Some("新元"), koyomi.next().unwrap().era_name());
+
+    assert_eq!(Ok(start), JapaneseEra::parse_date("新元元年4月1日"));
+    assert_eq!(Ok(date), JapaneseEra::parse_date("N2.1.1"));
+    assert!(JapaneseEra::parse_date("令和82年4月1日").is_err());
+}
+
+#[test]
+fn 最新の元号より前に始まる元号は登録できない() {
+    let start = NaiveDate::from_ymd_opt(2019, 4, 30).unwrap();
+    assert_eq!(
+        Err(RegisterEraError::InvalidStartDate),
+        JapaneseEra::register("旧元", "きゅうげん", "K", &start)
+    );
+}
+
+#[test]
+fn 既存の元号と重複する元号は登録できない() {
+    let start = NaiveDate::from_ymd_opt(2200, 1, 1).unwrap();
+    assert_eq!(
+        Err(RegisterEraError::DuplicateEra),
+        JapaneseEra::register("令和", "れいわ", "X", &start)
+    );
+    assert_eq!(
+        Err(RegisterEraError::DuplicateEra),
+        JapaneseEra::register("重複", "ちょうふく", "h", &start)
+    );
+}
+
+#[test]
+fn 名前が空の元号は登録できない() {
+    let start = NaiveDate::from_ymd_opt(2200, 1, 1).unwrap();
+    assert_eq!(
+        Err(RegisterEraError::InvalidName),
+        JapaneseEra::register("", "", "", &start)
+    );
+}
+
+#[test]
+fn 略称が英字1文字でない元号は登録できない() {
+    let start = NaiveDate::from_ymd_opt(2200, 1, 1).unwrap();
+    for abbreviation in ["AB", "1", "Ｎ", "あ"] {
+        assert_eq!(
+            Err(RegisterEraError::InvalidAbbreviation),
+            JapaneseEra::register("略称", "りゃくしょう", abbreviation, &start)
+        );
+    }
+}